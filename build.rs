@@ -1,3 +1,5 @@
+use std::{env, process::Command};
+
 #[cfg(not(feature = "testing-helpers"))]
 fn detect_nightly() {}
 
@@ -10,6 +12,27 @@ fn detect_nightly() {
     }
 }
 
+/// Detects whether `core::error::Error` is available (stabilized in Rust 1.81).
+///
+/// `rustc_version` crate is not used here, as it's an optional dependency.
+fn detect_error_in_core() {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let Ok(output) = Command::new(rustc).arg("--version").output() else {
+        return;
+    };
+    let version = String::from_utf8_lossy(&output.stdout);
+    // Output looks like `rustc 1.81.0 (eeb90cda1 2024-09-04)`.
+    let minor = version
+        .split_whitespace()
+        .nth(1)
+        .and_then(|v| v.split('.').nth(1))
+        .and_then(|minor| minor.parse::<u32>().ok());
+    if minor.is_some_and(|minor| minor >= 81) {
+        println!("cargo:rustc-cfg=error_in_core");
+    }
+}
+
 fn main() {
     detect_nightly();
+    detect_error_in_core();
 }
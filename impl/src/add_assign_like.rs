@@ -1,40 +1,131 @@
-use crate::add_helpers::{struct_exprs, tuple_exprs};
-use crate::utils::{add_extra_ty_param_bound_op, named_to_vec, unnamed_to_vec};
+use crate::add_helpers::{
+    skipped_where_clauses, struct_exprs, tuple_exprs, Attributes, Rhs, Skip,
+};
+use crate::utils::{
+    add_extra_ty_param_bound_op, add_extra_where_clauses, named_to_vec, unnamed_to_vec,
+};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
-use syn::{Data, DeriveInput, Fields};
+use quote::{format_ident, quote, ToTokens};
+#[cfg(feature = "add_assign")]
+use syn::Result;
+use syn::{Data, DeriveInput, Field, Fields, Index};
 
-pub fn expand(input: &DeriveInput, trait_name: &str) -> TokenStream {
+#[cfg(feature = "add_assign")]
+pub fn expand(input: &DeriveInput, trait_name: &str) -> Result<TokenStream> {
+    let attr_name = format_ident!(
+        "{}_assign",
+        trait_name.trim_end_matches("Assign").to_lowercase(),
+    );
+    let attrs = Attributes::parse(input, &attr_name, false)?;
+    Ok(expand_with_attrs(input, trait_name, &attrs))
+}
+
+/// Expands an [`AddAssign`]-like derive macro with the provided [`Attributes`], without parsing
+/// them.
+pub fn expand_with_attrs(
+    input: &DeriveInput,
+    trait_name: &str,
+    attrs: &Attributes,
+) -> TokenStream {
+    let rhs = &attrs.rhs;
     let trait_ident = format_ident!("{trait_name}");
     let method_name = trait_name.trim_end_matches("Assign").to_lowercase();
     let method_ident = format_ident!("{method_name}_assign");
     let input_type = &input.ident;
 
-    let generics = add_extra_ty_param_bound_op(&input.generics, &trait_ident);
+    let skipped = skipped_where_clauses(input, &attrs.skips, false);
+    let generics = match rhs {
+        // Skipped fields may use type parameters not supporting the operation at all, so the
+        // types of the operated fields are bounded instead.
+        Rhs::Fieldwise(None) if attrs.skips.any() => {
+            let tys = attrs.skips.operand_types(input);
+            add_extra_where_clauses(
+                &input.generics,
+                quote! {
+                    where #( #tys: derive_more::core::ops::#trait_ident, )*
+                          #( #skipped ),*
+                },
+            )
+        }
+        Rhs::Fieldwise(_) if attrs.skips.any() => add_extra_where_clauses(
+            &add_extra_ty_param_bound_op(&input.generics, &trait_ident),
+            quote! { where #( #skipped ),* },
+        ),
+        Rhs::Fieldwise(_) => add_extra_ty_param_bound_op(&input.generics, &trait_ident),
+        #[cfg(any(feature = "add", feature = "add_assign"))]
+        Rhs::Field { ty, field_ty, .. } => add_extra_where_clauses(
+            &input.generics,
+            quote! {
+                where #field_ty: derive_more::core::ops::#trait_ident<#ty>,
+                      #( #skipped ),*
+            },
+        ),
+    };
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let rhs_type = match rhs {
+        Rhs::Fieldwise(Some(ty)) => ty.to_token_stream(),
+        #[cfg(any(feature = "add", feature = "add_assign"))]
+        Rhs::Field { ty, .. } => ty.to_token_stream(),
+        Rhs::Fieldwise(None) => quote! { #input_type #ty_generics },
+    };
 
-    let exprs = match input.data {
-        Data::Struct(ref data_struct) => match data_struct.fields {
-            Fields::Unnamed(ref fields) => {
-                tuple_exprs(&unnamed_to_vec(fields), &method_ident)
-            }
-            Fields::Named(ref fields) => {
-                struct_exprs(&named_to_vec(fields), &method_ident)
-            }
-            _ => panic!("Unit structs cannot use derive({trait_name})"),
-        },
-
-        _ => panic!("Only structs can use derive({trait_name})"),
+    let Data::Struct(ref data_struct) = input.data else {
+        panic!("Only structs can use derive({trait_name})")
     };
+    let fields = &data_struct.fields;
+    let exprs = match (fields, rhs) {
+        (Fields::Unit, _) => panic!("Unit structs cannot use derive({trait_name})"),
+        #[cfg(any(feature = "add", feature = "add_assign"))]
+        (fields, Rhs::Field { index, .. }) => {
+            let field = fields.iter().nth(*index).unwrap();
+            let member = member(field, *index);
+            // generates `self.x.add_assign(rhs)`
+            vec![quote! { self.#member.#method_ident(rhs) }]
+        }
+        (Fields::Unnamed(ref fields), Rhs::Fieldwise(_)) => {
+            tuple_exprs(&unnamed_to_vec(fields), &method_ident)
+        }
+        (Fields::Named(ref fields), Rhs::Fieldwise(_)) => {
+            struct_exprs(&named_to_vec(fields), &method_ident)
+        }
+    };
+    let mut exprs: Vec<_> = match rhs {
+        Rhs::Fieldwise(_) => exprs
+            .into_iter()
+            .zip(attrs.skips.of(0))
+            .filter_map(|(expr, skip)| skip.is_none().then_some(expr))
+            .collect(),
+        #[cfg(any(feature = "add", feature = "add_assign"))]
+        Rhs::Field { .. } => exprs,
+    };
+    // Fields skipped with `default` are reset, while the kept ones are left untouched.
+    for (i, (field, skip)) in fields.iter().zip(attrs.skips.of(0)).enumerate() {
+        if *skip == Some(Skip::Default) {
+            let member = member(field, i);
+            // generates `self.x = Default::default()`
+            exprs.push(quote! {
+                self.#member = derive_more::core::default::Default::default()
+            });
+        }
+    }
 
     quote! {
         #[automatically_derived]
-        impl #impl_generics derive_more::#trait_ident for #input_type #ty_generics #where_clause {
+        impl #impl_generics derive_more::#trait_ident<#rhs_type> for #input_type #ty_generics #where_clause {
             #[inline]
             #[track_caller]
-            fn #method_ident(&mut self, rhs: #input_type #ty_generics) {
+            fn #method_ident(&mut self, rhs: #rhs_type) {
                 #( #exprs; )*
             }
         }
     }
 }
+
+/// Returns the member of the provided [`Field`] with the provided index, to be used in a field
+/// access expression.
+fn member(field: &Field, index: usize) -> TokenStream {
+    field.ident.as_ref().map_or_else(
+        || Index::from(index).to_token_stream(),
+        ToTokens::to_token_stream,
+    )
+}
@@ -1,7 +1,42 @@
+use crate::utils::attr::{self, ParseMultiple as _};
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+    feature = "not",
+))]
 use proc_macro2::TokenStream;
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+    feature = "not",
+))]
 use quote::quote;
-use syn::{Field, Ident, Index};
+#[cfg(any(feature = "add", feature = "mul", feature = "not"))]
+use quote::ToTokens;
+#[cfg(any(feature = "add", feature = "add_assign"))]
+use syn::ext::IdentExt as _;
+use syn::{
+    parse::{Parse, ParseStream},
+    token, Data, DeriveInput, Error, Fields, Ident, Result,
+};
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
+use syn::{Field, Index};
 
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
 pub fn tuple_exprs(fields: &[&Field], method_ident: &Ident) -> Vec<TokenStream> {
     let mut exprs = vec![];
 
@@ -14,6 +49,12 @@ pub fn tuple_exprs(fields: &[&Field], method_ident: &Ident) -> Vec<TokenStream>
     exprs
 }
 
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
 pub fn struct_exprs(fields: &[&Field], method_ident: &Ident) -> Vec<TokenStream> {
     let mut exprs = vec![];
 
@@ -26,3 +67,446 @@ pub fn struct_exprs(fields: &[&Field], method_ident: &Ident) -> Vec<TokenStream>
     }
     exprs
 }
+
+/// Parsed attributes of an `Add`-like derive macro.
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
+pub struct Attributes {
+    /// Right-hand side of the operation.
+    pub rhs: Rhs,
+
+    /// Indicator whether the operation should be additionally implemented for references.
+    #[cfg(any(feature = "add", feature = "mul"))]
+    pub by_ref: bool,
+
+    /// Fields skipped by the operation.
+    pub skips: Skips,
+}
+
+#[cfg(any(feature = "add", feature = "add_assign"))]
+impl Attributes {
+    /// Parses the [`Attributes`] of the provided [`DeriveInput`].
+    ///
+    /// `ref` argument is allowed only if `allow_ref` is `true`.
+    pub fn parse(
+        input: &DeriveInput,
+        attr_name: &Ident,
+        allow_ref: bool,
+    ) -> Result<Self> {
+        let attr = StructAttribute::parse_attrs(&input.attrs, attr_name)?;
+        let (ty, by_ref) = match attr {
+            Some(attr) => {
+                let span = attr.span;
+                let StructAttribute { rhs, by_ref } = attr.into_inner();
+                if by_ref && !allow_ref {
+                    return Err(Error::new(
+                        span,
+                        format!(
+                            "`ref` argument is not supported by `#[{attr_name}(...)]`"
+                        ),
+                    ));
+                }
+                (rhs, by_ref)
+            }
+            None => (None, false),
+        };
+
+        let (rhs, skips) = parse_fields(input, attr_name, ty)?;
+        if by_ref && !matches!(rhs, Rhs::Fieldwise(None)) {
+            return Err(Error::new(
+                input.ident.span(),
+                format!("`#[{attr_name}(ref)]` cannot be combined with custom right-hand side"),
+            ));
+        }
+        Ok(Self {
+            rhs,
+            #[cfg(any(feature = "add", feature = "mul"))]
+            by_ref,
+            skips,
+        })
+    }
+}
+
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
+impl Default for Attributes {
+    fn default() -> Self {
+        Self {
+            rhs: Rhs::Fieldwise(None),
+            #[cfg(any(feature = "add", feature = "mul"))]
+            by_ref: false,
+            skips: Skips::default(),
+        }
+    }
+}
+
+/// Right-hand side of an `Add`-like operation, as specified by `#[<op>(rhs = <type>)]` attribute
+/// placed on a struct, and `#[<op>(rhs)]` attribute placed on one of its fields.
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
+pub enum Rhs {
+    /// Operation is applied fieldwise against a value of the provided type (or `Self`, if
+    /// [`None`]) having the same shape.
+    Fieldwise(Option<syn::Type>),
+
+    /// Operation is applied to the designated field only, against a value of the provided type.
+    #[cfg(any(feature = "add", feature = "add_assign"))]
+    Field {
+        /// Index of the designated field.
+        index: usize,
+
+        /// Type of the right-hand side.
+        ty: Box<syn::Type>,
+
+        /// Type of the designated field.
+        field_ty: Box<syn::Type>,
+    },
+}
+
+/// Parses the [`Rhs`] and the [`Skips`] from the field attributes of the provided
+/// [`DeriveInput`], using the provided right-hand side type specified on the struct.
+#[cfg(any(feature = "add", feature = "add_assign"))]
+fn parse_fields(
+    input: &DeriveInput,
+    attr_name: &Ident,
+    ty: Option<syn::Type>,
+) -> Result<(Rhs, Skips)> {
+    let is_enum = matches!(input.data, Data::Enum(_));
+    if is_enum && ty.is_some() {
+        return Err(Error::new(
+            input.ident.span(),
+            format!("`#[{attr_name}(rhs = ...)]` attribute is not supported for enums"),
+        ));
+    }
+
+    let mut designated = None;
+    let mut skips = Skips::default();
+    for fields in variants_fields(input) {
+        let mut variant_skips = vec![];
+        for (index, field) in fields.iter().enumerate() {
+            let attr = FieldAttribute::parse_attrs(&field.attrs, attr_name)?;
+            variant_skips.push(match attr {
+                Some(attr) => {
+                    let span = attr.span;
+                    match attr.into_inner() {
+                        FieldAttribute::Skip(SkipAttribute(skip)) => Some(skip),
+                        FieldAttribute::Rhs if is_enum => {
+                            return Err(Error::new(
+                                span,
+                                format!(
+                                    "`#[{attr_name}(rhs)]` attribute is not supported for \
+                                     enums",
+                                ),
+                            ));
+                        }
+                        FieldAttribute::Rhs => {
+                            if designated.replace((index, &field.ty)).is_some() {
+                                return Err(Error::new(
+                                    span,
+                                    format!(
+                                        "only single field can be marked with \
+                                         `#[{attr_name}(rhs)]`",
+                                    ),
+                                ));
+                            }
+                            None
+                        }
+                    }
+                }
+                None => None,
+            });
+        }
+        skips.0.push(variant_skips);
+    }
+
+    let rhs = match designated {
+        Some((index, field_ty)) => Rhs::Field {
+            index,
+            ty: Box::new(ty.unwrap_or_else(|| field_ty.clone())),
+            field_ty: Box::new(field_ty.clone()),
+        },
+        None => Rhs::Fieldwise(ty),
+    };
+    Ok((rhs, skips))
+}
+
+/// Returns the [`Fields`] of the provided [`DeriveInput`]: a single one for a struct, or the ones
+/// of every variant for an enum.
+fn variants_fields(input: &DeriveInput) -> Vec<&Fields> {
+    match &input.data {
+        Data::Struct(data) => vec![&data.fields],
+        Data::Enum(data) => data.variants.iter().map(|v| &v.fields).collect(),
+        Data::Union(_) => vec![],
+    }
+}
+
+/// Way of obtaining the value of a field skipped by an operation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Skip {
+    /// Value of the left-hand side (or the only operand) is kept as is.
+    KeepLhs,
+
+    /// [`Default`] value of the field type is used.
+    Default,
+}
+
+/// [`Skip`]s of the fields of a struct, or of every enum variant, in their declaration order.
+#[derive(Default)]
+pub struct Skips(Vec<Vec<Option<Skip>>>);
+
+impl Skips {
+    /// Parses the [`Skips`] from the `#[<attr_name>(skip)]` attributes placed on the fields of the
+    /// provided [`DeriveInput`], disallowing any other arguments.
+    #[cfg(any(
+        feature = "mul",
+        feature = "mul_assign",
+        feature = "not",
+        feature = "sum",
+    ))]
+    pub fn parse(input: &DeriveInput, attr_name: &Ident) -> Result<Self> {
+        variants_fields(input)
+            .into_iter()
+            .map(|fields| {
+                fields
+                    .iter()
+                    .map(|field| {
+                        Ok(SkipAttribute::parse_attrs(&field.attrs, attr_name)?
+                            .map(|attr| attr.into_inner().0))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+
+    /// Returns the [`Skip`]s of the fields of the struct, or of the enum variant with the provided
+    /// index.
+    pub fn of(&self, variant: usize) -> &[Option<Skip>] {
+        self.0.get(variant).map_or(&[], Vec::as_slice)
+    }
+
+    /// Indicates whether any field is skipped.
+    pub fn any(&self) -> bool {
+        self.0.iter().flatten().any(Option::is_some)
+    }
+
+    /// Returns the types of the fields of the provided [`DeriveInput`], skipped with the provided
+    /// [`Skip`].
+    pub fn types<'a>(&self, input: &'a DeriveInput, skip: Skip) -> Vec<&'a syn::Type> {
+        self.fields(input, |s| s == Some(skip))
+    }
+
+    /// Returns the types of the fields of the provided [`DeriveInput`], not skipped by the
+    /// operation.
+    pub fn operand_types<'a>(&self, input: &'a DeriveInput) -> Vec<&'a syn::Type> {
+        self.fields(input, |s| s.is_none())
+    }
+
+    fn fields<'a>(
+        &self,
+        input: &'a DeriveInput,
+        filter: impl Fn(Option<Skip>) -> bool,
+    ) -> Vec<&'a syn::Type> {
+        let mut tys = vec![];
+        for (i, fields) in variants_fields(input).into_iter().enumerate() {
+            for (field, skip) in fields.iter().zip(self.of(i)) {
+                if filter(*skip) && !tys.contains(&&field.ty) {
+                    tys.push(&field.ty);
+                }
+            }
+        }
+        tys
+    }
+}
+
+/// Representation of an `Add`-like derive macro attribute, placed on a struct.
+///
+/// ```rust,ignore
+/// #[<op>(rhs = <type>)]
+/// #[<op>(ref)]
+/// #[<op>(rhs = <type>, ref)]
+/// ```
+#[cfg(any(feature = "add", feature = "add_assign"))]
+#[derive(Default)]
+struct StructAttribute {
+    /// Type of the right-hand side of the operation.
+    rhs: Option<syn::Type>,
+
+    /// Indicator whether the operation should be additionally implemented for references.
+    by_ref: bool,
+}
+
+#[cfg(any(feature = "add", feature = "add_assign"))]
+impl Parse for StructAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.call(Ident::parse_any)?;
+            match ident.to_string().as_str() {
+                "rhs" => {
+                    input.parse::<token::Eq>()?;
+                    if out.rhs.replace(input.parse()?).is_some() {
+                        return Err(Error::new(
+                            ident.span(),
+                            "only single `rhs = ...` argument is allowed here",
+                        ));
+                    }
+                }
+                "ref" => out.by_ref = true,
+                _ => return Err(Error::new(
+                    ident.span(),
+                    "unknown argument, only `rhs = <type>` and `ref` are allowed here",
+                )),
+            }
+            if !input.is_empty() {
+                input.parse::<token::Comma>()?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(any(feature = "add", feature = "add_assign"))]
+impl attr::ParseMultiple for StructAttribute {}
+
+/// Representation of an `Add`-like derive macro attribute, placed on a field.
+///
+/// ```rust,ignore
+/// #[<op>(rhs)]
+/// #[<op>(skip)]
+/// #[<op>(skip, keep = "lhs")]
+/// #[<op>(skip, default)]
+/// ```
+#[cfg(any(feature = "add", feature = "add_assign"))]
+enum FieldAttribute {
+    /// Designates the field to apply the operation to.
+    Rhs,
+
+    /// Skips the field from the operation.
+    Skip(SkipAttribute),
+}
+
+#[cfg(any(feature = "add", feature = "add_assign"))]
+impl Parse for FieldAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let ident = input.fork().parse::<Ident>()?;
+        match ident.to_string().as_str() {
+            "rhs" => {
+                input.parse::<Ident>()?;
+                Ok(Self::Rhs)
+            }
+            "skip" => input.parse().map(Self::Skip),
+            _ => Err(Error::new(
+                ident.span(),
+                "unknown argument, only `rhs` and `skip` are allowed here",
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "add", feature = "add_assign"))]
+impl attr::ParseMultiple for FieldAttribute {}
+
+/// Representation of a `skip` attribute of an operation derive macro, placed on a field.
+///
+/// ```rust,ignore
+/// #[<op>(skip)]
+/// #[<op>(skip, keep = "lhs")]
+/// #[<op>(skip, default)]
+/// ```
+struct SkipAttribute(Skip);
+
+impl Parse for SkipAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        if ident != "skip" {
+            return Err(Error::new(
+                ident.span(),
+                "unknown argument, only `skip` is allowed here",
+            ));
+        }
+        if input.is_empty() {
+            return Ok(Self(Skip::KeepLhs));
+        }
+        input.parse::<token::Comma>()?;
+
+        let ident = input.parse::<Ident>()?;
+        let skip = match ident.to_string().as_str() {
+            "keep" => {
+                input.parse::<token::Eq>()?;
+                let lit = input.parse::<syn::LitStr>()?;
+                if lit.value() != "lhs" {
+                    return Err(Error::new(
+                        lit.span(),
+                        "only `keep = \"lhs\"` is supported",
+                    ));
+                }
+                Skip::KeepLhs
+            }
+            "default" => Skip::Default,
+            _ => return Err(Error::new(
+                ident.span(),
+                "unknown argument, only `keep = \"lhs\"` and `default` are allowed \
+                     after `skip`",
+            )),
+        };
+        if !input.is_empty() {
+            input.parse::<token::Comma>()?;
+        }
+        Ok(Self(skip))
+    }
+}
+
+impl attr::ParseMultiple for SkipAttribute {}
+
+/// Generates an expression for the value of a field skipped with the provided [`Skip`], having
+/// the provided left-hand side value.
+#[cfg(any(feature = "add", feature = "mul", feature = "not"))]
+pub fn skipped_expr(skip: Skip, lhs: impl ToTokens) -> TokenStream {
+    match skip {
+        Skip::KeepLhs => lhs.into_token_stream(),
+        Skip::Default => quote! { derive_more::core::default::Default::default() },
+    }
+}
+
+/// Generates where clauses for the fields of the provided [`DeriveInput`], skipped with the
+/// provided [`Skips`]: `Default` ones should implement [`Default`], and the kept ones should
+/// implement [`Clone`], if `clone_kept` is `true`.
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+    feature = "not",
+))]
+pub fn skipped_where_clauses(
+    input: &DeriveInput,
+    skips: &Skips,
+    clone_kept: bool,
+) -> Vec<TokenStream> {
+    let defaults = skips.types(input, Skip::Default);
+    let mut clauses = defaults
+        .iter()
+        .map(|ty| quote! { #ty: derive_more::core::default::Default })
+        .collect::<Vec<_>>();
+    if clone_kept {
+        let kept = skips.types(input, Skip::KeepLhs);
+        clauses.extend(
+            kept.iter()
+                .map(|ty| quote! { #ty: derive_more::core::clone::Clone }),
+        );
+    }
+    clauses
+}
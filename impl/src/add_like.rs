@@ -1,65 +1,280 @@
-use crate::add_helpers::{struct_exprs, tuple_exprs};
+use crate::add_helpers::{
+    skipped_expr, skipped_where_clauses, struct_exprs, tuple_exprs, Attributes, Rhs,
+    Skip,
+};
 use crate::utils::{
-    add_extra_type_param_bound_op_output, field_idents, named_to_vec, numbered_vars,
-    unnamed_to_vec,
+    add_extra_type_param_bound_op_output, add_extra_where_clauses, field_idents,
+    named_to_vec, numbered_vars, unnamed_to_vec, RefType,
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use std::iter;
-use syn::{Data, DataEnum, DeriveInput, Field, Fields, Ident};
+#[cfg(feature = "add")]
+use syn::Result;
+use syn::{parse_quote, Data, DataEnum, DeriveInput, Field, Fields, Ident, Index};
 
-pub fn expand(input: &DeriveInput, trait_name: &str) -> TokenStream {
+#[cfg(feature = "add")]
+pub fn expand(input: &DeriveInput, trait_name: &str) -> Result<TokenStream> {
+    let trait_name = trait_name.trim_end_matches("Self");
+    let attr_name = format_ident!("{}", trait_name.to_lowercase());
+    let attrs = Attributes::parse(input, &attr_name, true)?;
+    Ok(expand_with_attrs(input, trait_name, &attrs))
+}
+
+/// Expands an [`Add`]-like derive macro with the provided [`Attributes`], without parsing them.
+pub fn expand_with_attrs(
+    input: &DeriveInput,
+    trait_name: &str,
+    attrs: &Attributes,
+) -> TokenStream {
+    let rhs = &attrs.rhs;
     let trait_name = trait_name.trim_end_matches("Self");
     let trait_ident = format_ident!("{trait_name}");
     let method_name = trait_name.to_lowercase();
     let method_ident = format_ident!("{method_name}");
     let input_type = &input.ident;
 
-    let generics = add_extra_type_param_bound_op_output(&input.generics, &trait_ident);
+    let skipped = skipped_where_clauses(input, &attrs.skips, false);
+    let generics = match rhs {
+        // Skipped fields may use type parameters not supporting the operation at all, so the
+        // types of the operated fields are bounded instead.
+        Rhs::Fieldwise(None) if attrs.skips.any() => {
+            let tys = attrs.skips.operand_types(input);
+            add_extra_where_clauses(
+                &input.generics,
+                quote! {
+                    where #( #tys: derive_more::core::ops::#trait_ident<Output = #tys>, )*
+                          #( #skipped ),*
+                },
+            )
+        }
+        Rhs::Fieldwise(_) if attrs.skips.any() => add_extra_where_clauses(
+            &add_extra_type_param_bound_op_output(&input.generics, &trait_ident),
+            quote! { where #( #skipped ),* },
+        ),
+        Rhs::Fieldwise(_) => {
+            add_extra_type_param_bound_op_output(&input.generics, &trait_ident)
+        }
+        #[cfg(any(feature = "add", feature = "add_assign"))]
+        Rhs::Field { ty, field_ty, .. } => add_extra_where_clauses(
+            &input.generics,
+            quote! {
+                where #field_ty: derive_more::core::ops::#trait_ident<#ty, Output = #field_ty>,
+                      #( #skipped ),*
+            },
+        ),
+    };
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let rhs_type = match rhs {
+        Rhs::Fieldwise(Some(ty)) => ty.to_token_stream(),
+        #[cfg(any(feature = "add", feature = "add_assign"))]
+        Rhs::Field { ty, .. } => ty.to_token_stream(),
+        Rhs::Fieldwise(None) => quote! { #input_type #ty_generics },
+    };
 
+    let skips = attrs.skips.of(0);
     let (output_type, block) = match input.data {
-        Data::Struct(ref data_struct) => match data_struct.fields {
-            Fields::Unnamed(ref fields) => (
+        Data::Struct(ref data_struct) => match (&data_struct.fields, rhs) {
+            (Fields::Unit, _) => panic!("Unit structs cannot use derive({trait_name})"),
+            #[cfg(any(feature = "add", feature = "add_assign"))]
+            (fields, Rhs::Field { index, .. }) => (
                 quote! { #input_type #ty_generics },
-                tuple_content(input_type, &unnamed_to_vec(fields), &method_ident),
+                designated_field_content(
+                    input_type,
+                    fields,
+                    *index,
+                    &method_ident,
+                    skips,
+                ),
             ),
-            Fields::Named(ref fields) => (
+            (Fields::Unnamed(ref fields), Rhs::Fieldwise(_)) => (
                 quote! { #input_type #ty_generics },
-                struct_content(input_type, &named_to_vec(fields), &method_ident),
+                tuple_content(
+                    input_type,
+                    &unnamed_to_vec(fields),
+                    &method_ident,
+                    skips,
+                ),
+            ),
+            (Fields::Named(ref fields), Rhs::Fieldwise(_)) => (
+                quote! { #input_type #ty_generics },
+                struct_content(input_type, &named_to_vec(fields), &method_ident, skips),
             ),
-            _ => panic!("Unit structs cannot use derive({trait_name})"),
         },
         Data::Enum(ref data_enum) => (
             quote! {
                 derive_more::core::result::Result<#input_type #ty_generics, derive_more::BinaryError>
             },
-            enum_content(input_type, data_enum, &method_ident),
+            enum_content(input_type, data_enum, &method_ident, attrs, false),
         ),
 
         _ => panic!("Only structs and enums can use derive({trait_name})"),
     };
 
+    let ref_impls = attrs
+        .by_ref
+        .then(|| ref_impls(input, &trait_ident, &method_ident, &output_type, attrs));
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics derive_more::#trait_ident<#rhs_type> for #input_type #ty_generics #where_clause {
+            type Output = #output_type;
+
+            #[inline]
+            #[track_caller]
+            fn #method_ident(self, rhs: #rhs_type) -> #output_type {
+                #block
+            }
+        }
+
+        #ref_impls
+    }
+}
+
+/// Generates implementations of an [`Add`]-like trait for references (`&T + &T`, `T + &T` and
+/// `&T + T`), applying the operation to the references of the fields.
+fn ref_impls(
+    input: &DeriveInput,
+    trait_ident: &Ident,
+    method_ident: &Ident,
+    output_type: &TokenStream,
+    attrs: &Attributes,
+) -> TokenStream {
+    let input_type = &input.ident;
+    let lifetime = RefType::Ref.lifetime();
+    let reference = RefType::Ref.reference_with_lifetime();
+
+    let block = match &input.data {
+        Data::Struct(data) => {
+            ref_content(input_type, &data.fields, method_ident, attrs.skips.of(0))
+        }
+        Data::Enum(data) => enum_content(input_type, data, method_ident, attrs, true),
+        Data::Union(_) => unreachable!("unions are rejected already"),
+    };
+    let tys = attrs.skips.operand_types(input);
+    let tys = tys.iter();
+    let skipped = skipped_where_clauses(input, &attrs.skips, true);
+
+    let generics = add_extra_where_clauses(
+        &input.generics,
+        quote! {
+            where #(
+                for<'__deriveMoreField> &'__deriveMoreField #tys: derive_more::core::ops::#trait_ident<
+                    &'__deriveMoreField #tys, Output = #tys,
+                >,
+            )*
+            #( #skipped ),*
+        },
+    );
+    let (_, _, where_clause) = generics.split_for_impl();
+    let mut ref_generics = generics.clone();
+    ref_generics.params.insert(0, parse_quote! { #lifetime });
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let self_ty = quote! { #input_type #ty_generics };
+
     quote! {
         #[automatically_derived]
-        impl #impl_generics derive_more::#trait_ident for #input_type #ty_generics #where_clause {
+        impl #ref_impl_generics derive_more::#trait_ident<#reference #self_ty>
+         for #reference #self_ty #where_clause
+        {
             type Output = #output_type;
 
             #[inline]
             #[track_caller]
-            fn #method_ident(self, rhs: #input_type #ty_generics) -> #output_type {
+            fn #method_ident(self, rhs: #reference #self_ty) -> #output_type {
                 #block
             }
         }
+
+        #[automatically_derived]
+        impl #ref_impl_generics derive_more::#trait_ident<#reference #self_ty>
+         for #self_ty #where_clause
+        {
+            type Output = #output_type;
+
+            #[inline]
+            #[track_caller]
+            fn #method_ident(self, rhs: #reference #self_ty) -> #output_type {
+                derive_more::#trait_ident::#method_ident(&self, rhs)
+            }
+        }
+
+        #[automatically_derived]
+        impl #ref_impl_generics derive_more::#trait_ident<#self_ty>
+         for #reference #self_ty #where_clause
+        {
+            type Output = #output_type;
+
+            #[inline]
+            #[track_caller]
+            fn #method_ident(self, rhs: #self_ty) -> #output_type {
+                derive_more::#trait_ident::#method_ident(self, &rhs)
+            }
+        }
     }
 }
 
+fn ref_content(
+    input_type: &Ident,
+    fields: &Fields,
+    method_ident: &Ident,
+    skips: &[Option<Skip>],
+) -> TokenStream {
+    let members = fields.iter().enumerate().map(|(i, field)| {
+        let member = field.ident.as_ref().map_or_else(
+            || Index::from(i).to_token_stream(),
+            ToTokens::to_token_stream,
+        );
+        if let Some(skip) = skips.get(i).copied().flatten() {
+            let lhs = quote! { derive_more::core::clone::Clone::clone(&self.#member) };
+            let expr = skipped_expr(skip, lhs);
+            return quote! { #member: #expr };
+        }
+        // generates `x: (&self.x).add(&rhs.x)`
+        quote! { #member: (&self.#member).#method_ident(&rhs.#member) }
+    });
+    quote! { #input_type { #( #members ),* } }
+}
+
+#[cfg(any(feature = "add", feature = "add_assign"))]
+fn designated_field_content(
+    input_type: &Ident,
+    fields: &Fields,
+    index: usize,
+    method_ident: &Ident,
+    skips: &[Option<Skip>],
+) -> TokenStream {
+    let members = fields.iter().enumerate().map(|(i, field)| {
+        let member = field.ident.as_ref().map_or_else(
+            || Index::from(i).to_token_stream(),
+            ToTokens::to_token_stream,
+        );
+        let expr = if i == index {
+            // generates `self.x.add(rhs)`
+            quote! { self.#member.#method_ident(rhs) }
+        } else {
+            let skip = skips.get(i).copied().flatten();
+            skipped_expr(skip.unwrap_or(Skip::KeepLhs), quote! { self.#member })
+        };
+        quote! { #member: #expr }
+    });
+    quote! { #input_type { #( #members ),* } }
+}
+
 fn tuple_content<T: ToTokens>(
     input_type: &T,
     fields: &[&Field],
     method_ident: &Ident,
+    skips: &[Option<Skip>],
 ) -> TokenStream {
     let exprs = tuple_exprs(fields, method_ident);
+    let exprs = exprs.into_iter().enumerate().map(|(i, expr)| {
+        let member = Index::from(i);
+        skips.get(i).copied().flatten().map_or(expr, |skip| {
+            // generates `self.0`
+            skipped_expr(skip, quote! { self.#member })
+        })
+    });
     quote! { #input_type(#(#exprs),*) }
 }
 
@@ -67,10 +282,22 @@ fn struct_content(
     input_type: &Ident,
     fields: &[&Field],
     method_ident: &Ident,
+    skips: &[Option<Skip>],
 ) -> TokenStream {
     // It's safe to unwrap because struct fields always have an identifier
     let exprs = struct_exprs(fields, method_ident);
     let field_names = field_idents(fields);
+    let exprs =
+        exprs
+            .into_iter()
+            .zip(&field_names)
+            .enumerate()
+            .map(|(i, (expr, name))| {
+                skips.get(i).copied().flatten().map_or(expr, |skip| {
+                    // generates `self.x`
+                    skipped_expr(skip, quote! { self.#name })
+                })
+            });
 
     quote! { #input_type{#(#field_names: #exprs),*} }
 }
@@ -80,27 +307,28 @@ fn enum_content(
     input_type: &Ident,
     data_enum: &DataEnum,
     method_ident: &Ident,
+    attrs: &Attributes,
+    by_ref: bool,
 ) -> TokenStream {
     let mut matches = vec![];
-    let mut method_iter = iter::repeat(method_ident);
 
-    for variant in &data_enum.variants {
+    for (i, variant) in data_enum.variants.iter().enumerate() {
         let subtype = &variant.ident;
         let subtype = quote! { #input_type::#subtype };
+        let skips = attrs.skips.of(i);
 
         match variant.fields {
             Fields::Unnamed(ref fields) => {
                 // The pattern that is outputted should look like this:
                 // (Subtype(left_vars), TypePath(right_vars)) => Ok(TypePath(exprs))
                 let size = unnamed_to_vec(fields).len();
-                let l_vars = &numbered_vars(size, "l_");
-                let r_vars = &numbered_vars(size, "r_");
-                let method_iter = method_iter.by_ref();
+                let (l_pats, r_pats, exprs) =
+                    enum_field_parts(size, skips, method_ident, by_ref);
                 let matcher = quote! {
-                    (#subtype(#(#l_vars),*),
-                     #subtype(#(#r_vars),*)) => {
+                    (#subtype(#(#l_pats),*),
+                     #subtype(#(#r_pats),*)) => {
                         derive_more::core::result::Result::Ok(
-                            #subtype(#(#l_vars.#method_iter(#r_vars)),*)
+                            #subtype(#(#exprs),*)
                         )
                     }
                 };
@@ -114,14 +342,13 @@ fn enum_content(
                 let field_vec = named_to_vec(fields);
                 let size = field_vec.len();
                 let field_names = &field_idents(&field_vec);
-                let l_vars = &numbered_vars(size, "l_");
-                let r_vars = &numbered_vars(size, "r_");
-                let method_iter = method_iter.by_ref();
+                let (l_pats, r_pats, exprs) =
+                    enum_field_parts(size, skips, method_ident, by_ref);
                 let matcher = quote! {
-                    (#subtype{#(#field_names: #l_vars),*},
-                     #subtype{#(#field_names: #r_vars),*}) => {
+                    (#subtype{#(#field_names: #l_pats),*},
+                     #subtype{#(#field_names: #r_pats),*}) => {
                         derive_more::core::result::Result::Ok(#subtype{
-                            #(#field_names: #l_vars.#method_iter(#r_vars)),*
+                            #(#field_names: #exprs),*
                         })
                     }
                 };
@@ -156,3 +383,43 @@ fn enum_content(
         }
     }
 }
+
+/// Generates the left-hand side and right-hand side patterns binding the fields of an enum
+/// variant, along with the expressions computing them, considering the provided [`Skip`]s.
+fn enum_field_parts(
+    size: usize,
+    skips: &[Option<Skip>],
+    method_ident: &Ident,
+    by_ref: bool,
+) -> (Vec<TokenStream>, Vec<TokenStream>, Vec<TokenStream>) {
+    let l_vars = numbered_vars(size, "l_");
+    let r_vars = numbered_vars(size, "r_");
+    let mut parts = (vec![], vec![], vec![]);
+    for (i, (l_var, r_var)) in l_vars.iter().zip(&r_vars).enumerate() {
+        let (l_pat, r_pat, expr) = match skips.get(i).copied().flatten() {
+            None => (
+                quote! { #l_var },
+                quote! { #r_var },
+                quote! { #l_var.#method_ident(#r_var) },
+            ),
+            Some(Skip::KeepLhs) if by_ref => (
+                quote! { #l_var },
+                quote! { _ },
+                quote! { derive_more::core::clone::Clone::clone(#l_var) },
+            ),
+            Some(skip) => (
+                if skip == Skip::KeepLhs {
+                    quote! { #l_var }
+                } else {
+                    quote! { _ }
+                },
+                quote! { _ },
+                skipped_expr(skip, l_var),
+            ),
+        };
+        parts.0.push(l_pat);
+        parts.1.push(r_pat);
+        parts.2.push(expr);
+    }
+    parts
+}
@@ -0,0 +1,293 @@
+//! Implementation of a [`Builder`] derive macro.
+
+use std::collections::HashSet;
+
+use crate::constructor::{parse_field_attrs, FieldAttribute};
+use convert_case::{Case, Casing as _};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens as _};
+use syn::{spanned::Spanned as _, Data, DeriveInput, Fields, Result};
+
+/// Expands a [`Builder`] derive macro.
+pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            format!("`{trait_name}` can only be derived for structs"),
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            data.fields.span(),
+            format!("`{trait_name}` can only be derived for structs with named fields"),
+        ));
+    };
+
+    // Struct-level `#[new(...)]` attributes describe named constructors of the `Constructor`
+    // derive only, so they're ignored here.
+    let attr_name = format_ident!("new");
+    let field_attrs = parse_field_attrs(&data.fields, &attr_name)?;
+
+    let ty = &input.ident;
+    let vis = &input.vis;
+    let builder_ty = format_ident!("{ty}Builder");
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let ty_params = input
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Type(ty) => ty.ident.to_token_stream(),
+            syn::GenericParam::Lifetime(lf) => lf.lifetime.to_token_stream(),
+            syn::GenericParam::Const(c) => c.ident.to_token_stream(),
+        })
+        .collect::<Vec<_>>();
+
+    // Names of the state generic parameters must clash neither with the existing generic
+    // parameters, nor with each other (like `foo_bar` and `foo__bar` fields do).
+    let mut taken_names = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(ty) => Some(ty.ident.to_string()),
+            syn::GenericParam::Const(c) => Some(c.ident.to_string()),
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect::<HashSet<_>>();
+    let fields = fields
+        .named
+        .iter()
+        .zip(&field_attrs)
+        .map(|(field, attr)| {
+            let ident = field.ident.as_ref().unwrap();
+            let mut state = format!("__{}", ident.to_string().to_case(Case::Pascal));
+            while taken_names.contains(&state) {
+                state.insert(0, '_');
+            }
+            taken_names.insert(state.clone());
+            BuilderField {
+                field,
+                ident,
+                state: format_ident!("{state}"),
+                attr: attr.as_ref(),
+            }
+        })
+        .collect::<Vec<_>>();
+    let required = fields
+        .iter()
+        .filter(|f| f.is_required())
+        .collect::<Vec<_>>();
+
+    let with_states = |states: &mut dyn Iterator<Item = TokenStream>| -> TokenStream {
+        let states = states.collect::<Vec<_>>();
+        quote! { #builder_ty<#( #ty_params, )* #( #states ),*> }
+    };
+    let generics_with_states = |states: &mut dyn Iterator<Item = &syn::Ident>| {
+        let mut generics = input.generics.clone();
+        generics
+            .params
+            .extend(states.map(|state| -> syn::GenericParam {
+                syn::parse_quote! { #state }
+            }));
+        generics
+    };
+
+    let all_states = required.iter().map(|f| &f.state).collect::<Vec<_>>();
+    let struct_generics = generics_with_states(&mut all_states.iter().copied());
+    let struct_params = &struct_generics.params;
+    let storage = fields.iter().map(|f| {
+        let ident = f.ident;
+        let ty = &f.field.ty;
+        if f.is_required() {
+            let state = &f.state;
+            quote! { #ident: #state }
+        } else {
+            quote! { #ident: derive_more::core::option::Option<#ty> }
+        }
+    });
+    let unset_builder_ty = with_states(&mut required.iter().map(|_| quote! { () }));
+    let unset_values = fields.iter().map(|f| {
+        let ident = f.ident;
+        if f.is_required() {
+            quote! { #ident: () }
+        } else {
+            quote! { #ident: derive_more::core::option::Option::None }
+        }
+    });
+
+    let required_setters = required.iter().map(|f| {
+        let ident = f.ident;
+        let field_ty = &f.field.ty;
+        let docs = f.docs();
+        let other_states = all_states.iter().copied().filter(|s| **s != f.state);
+        let generics = generics_with_states(&mut other_states.clone());
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let self_ty = with_states(&mut all_states.iter().map(|s| {
+            if **s == f.state {
+                quote! { () }
+            } else {
+                s.to_token_stream()
+            }
+        }));
+        let out_ty = with_states(&mut all_states.iter().map(|s| {
+            if **s == f.state {
+                quote! { (#field_ty,) }
+            } else {
+                s.to_token_stream()
+            }
+        }));
+        let (param_ty, value) = if f.is_into() {
+            (
+                quote! { impl derive_more::core::convert::Into<#field_ty> },
+                quote! { derive_more::core::convert::Into::<#field_ty>::into(#ident) },
+            )
+        } else {
+            (field_ty.to_token_stream(), ident.to_token_stream())
+        };
+        let moved = fields
+            .iter()
+            .filter(|other| other.ident != ident)
+            .map(|other| {
+                let ident = other.ident;
+                quote! { #ident: self.#ident }
+            });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #self_ty #where_clause {
+                #( #docs )*
+                #[inline]
+                #vis fn #ident(self, #ident: #param_ty) -> #out_ty {
+                    #builder_ty {
+                        #ident: (#value,),
+                        #( #moved, )*
+                        __derive_more_phantom: derive_more::core::marker::PhantomData,
+                    }
+                }
+            }
+        }
+    });
+
+    let optional_setters = fields.iter().filter(|f| !f.is_required()).map(|f| {
+        let ident = f.ident;
+        let field_ty = &f.field.ty;
+        let docs = f.docs();
+        quote! {
+            #( #docs )*
+            #[inline]
+            #vis fn #ident(mut self, #ident: #field_ty) -> Self {
+                self.#ident = derive_more::core::option::Option::Some(#ident);
+                self
+            }
+        }
+    });
+    let (struct_impl_generics, _, _) = struct_generics.split_for_impl();
+    let any_builder_ty =
+        with_states(&mut all_states.iter().map(|s| s.to_token_stream()));
+
+    let set_builder_ty = with_states(&mut required.iter().map(|f| {
+        let ty = &f.field.ty;
+        quote! { (#ty,) }
+    }));
+    let built_values = fields.iter().map(|f| {
+        let ident = f.ident;
+        let ty = &f.field.ty;
+        match f.attr {
+            None | Some(FieldAttribute::Into) => quote! { #ident: self.#ident.0 },
+            Some(FieldAttribute::Default) => quote! {
+                #ident: match self.#ident {
+                    derive_more::core::option::Option::Some(value) => value,
+                    derive_more::core::option::Option::None => {
+                        <#ty as derive_more::core::default::Default>::default()
+                    }
+                }
+            },
+            Some(FieldAttribute::Value(expr)) => quote! {
+                #ident: match self.#ident {
+                    derive_more::core::option::Option::Some(value) => value,
+                    derive_more::core::option::Option::None => #expr,
+                }
+            },
+        }
+    });
+
+    let builder_doc = format!(
+        "Builder of a [`{ty}`] value, which can be built only once all its required fields \
+         are set.",
+    );
+    let builder_fn_doc = format!("Creates a new [`{builder_ty}`] with no fields set.");
+    let build_doc = format!("Builds a [`{ty}`] value out of the set fields.");
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #[must_use]
+        #vis struct #builder_ty<#struct_params> #where_clause {
+            #( #storage, )*
+            __derive_more_phantom: derive_more::core::marker::PhantomData<fn() -> #ty #ty_generics>,
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            #[inline]
+            #vis fn builder() -> #unset_builder_ty {
+                #builder_ty {
+                    #( #unset_values, )*
+                    __derive_more_phantom: derive_more::core::marker::PhantomData,
+                }
+            }
+        }
+
+        #( #required_setters )*
+
+        #[automatically_derived]
+        impl #struct_impl_generics #any_builder_ty #where_clause {
+            #( #optional_setters )*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #set_builder_ty #where_clause {
+            #[doc = #build_doc]
+            #[inline]
+            #vis fn build(self) -> #ty #ty_generics {
+                #ty {
+                    #( #built_values, )*
+                }
+            }
+        }
+    })
+}
+
+/// Field of a struct, set by a generated builder.
+struct BuilderField<'a> {
+    /// Original [`syn::Field`].
+    field: &'a syn::Field,
+
+    /// Name of the field.
+    ident: &'a syn::Ident,
+
+    /// Name of the generic parameter, tracking whether this field is set already.
+    state: syn::Ident,
+
+    /// Parsed `#[new(...)]` attribute of the field, shared with the `Constructor` derive.
+    attr: Option<&'a FieldAttribute>,
+}
+
+impl BuilderField<'_> {
+    /// Indicates whether this field must be set before building.
+    fn is_required(&self) -> bool {
+        matches!(self.attr, None | Some(FieldAttribute::Into))
+    }
+
+    /// Indicates whether the setter of this field accepts anything convertible [`Into`] its
+    /// type.
+    fn is_into(&self) -> bool {
+        matches!(self.attr, Some(FieldAttribute::Into))
+    }
+
+    /// Returns the doc comments of this field to be forwarded to its setter.
+    fn docs(&self) -> impl Iterator<Item = &syn::Attribute> + Clone {
+        self.field.attrs.iter().filter(|a| a.path().is_ident("doc"))
+    }
+}
@@ -1,50 +1,485 @@
+//! Implementation of a [`Constructor`] derive macro.
+
 use crate::utils::{
-    field_idents, get_field_types, named_to_vec, numbered_vars, unnamed_to_vec,
+    attr::{self, ParseMultiple as _},
+    Spanning,
 };
+use convert_case::{Case, Casing as _};
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Data, DeriveInput, Field, Fields, Ident};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token, Data, DeriveInput, Fields, Result,
+};
 
-/// Provides the hook to expand `#[derive(Constructor)]` into an implementation of `Constructor`
-pub fn expand(input: &DeriveInput, _: &str) -> TokenStream {
+/// Expands a [`Constructor`] derive macro.
+pub fn expand(input: &DeriveInput, _: &str) -> Result<TokenStream> {
+    let attr_name = format_ident!("new");
     let input_type = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-    let ((body, vars), fields) = match input.data {
-        Data::Struct(ref data_struct) => match data_struct.fields {
-            Fields::Unnamed(ref fields) => {
-                let field_vec = unnamed_to_vec(fields);
-                (tuple_body(input_type, &field_vec), field_vec)
-            }
-            Fields::Named(ref fields) => {
-                let field_vec = named_to_vec(fields);
-                (struct_body(input_type, &field_vec), field_vec)
-            }
-            Fields::Unit => (struct_body(input_type, &[]), vec![]),
-        },
-        _ => panic!("Only structs can derive a constructor"),
+
+    let funcs = match &input.data {
+        Data::Struct(data) => constructors(
+            &data.fields,
+            &input.attrs,
+            &attr_name,
+            quote! { #input_type },
+            &format_ident!("new"),
+            None,
+        )?,
+        Data::Enum(data) => {
+            let enum_attrs = Attribute::parse_all(&input.attrs, &attr_name)?;
+            if let Some(span) = enum_attrs.iter().find_map(|attr| {
+                attr.name
+                    .as_ref()
+                    .map(syn::Ident::span)
+                    .or(attr.fields.as_ref().map(Spanning::span))
+            }) {
+                return Err(syn::Error::new(
+                    span,
+                    "`name` and `fields` arguments are allowed only on enum variants",
+                ));
+            }
+            if let Some(attr) = enum_attrs.get(1) {
+                return Err(syn::Error::new(
+                    attr.span,
+                    "only single `#[new(...)]` attribute is allowed on an enum",
+                ));
+            }
+            let vis = enum_attrs
+                .into_iter()
+                .next()
+                .and_then(|attr| attr.into_inner().vis);
+
+            let mut funcs = vec![];
+            for variant in &data.variants {
+                let variant_ident = &variant.ident;
+                let default_name = format_ident!(
+                    "new_{}",
+                    variant_ident.to_string().to_case(Case::Snake),
+                    span = variant_ident.span(),
+                );
+                funcs.extend(constructors(
+                    &variant.fields,
+                    &variant.attrs,
+                    &attr_name,
+                    quote! { #input_type :: #variant_ident },
+                    &default_name,
+                    vis.as_ref(),
+                )?);
+            }
+            funcs
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`Constructor` cannot be derived for unions",
+            ))
+        }
     };
-    let original_types = &get_field_types(&fields);
-    quote! {
+
+    Ok(quote! {
         #[allow(missing_docs)]
         #[automatically_derived]
         impl #impl_generics #input_type #ty_generics #where_clause {
-            #[inline]
-            pub const fn new(#(#vars: #original_types),*) -> #input_type #ty_generics {
-                #body
+            #( #funcs )*
+        }
+    })
+}
+
+/// Generates constructors of the provided [`Fields`], declared by the `#[new(...)]` attributes
+/// of the struct or the enum variant.
+///
+/// A single constructor with the `default_name` is generated if none is declared.
+fn constructors(
+    fields: &Fields,
+    attrs: &[syn::Attribute],
+    attr_name: &syn::Ident,
+    path: TokenStream,
+    default_name: &syn::Ident,
+    default_vis: Option<&syn::Visibility>,
+) -> Result<Vec<TokenStream>> {
+    let field_attrs = parse_field_attrs(fields, attr_name)?;
+    let mut attrs = Attribute::parse_all(attrs, attr_name)?;
+    if attrs.is_empty() {
+        attrs.push(Spanning::new(Attribute::default(), path.span()));
+    }
+
+    attrs
+        .iter()
+        .map(|attr| {
+            let fields = ConstructedFields::new(
+                fields,
+                &field_attrs,
+                attr.fields.as_ref().map(|fields| fields.as_slice()),
+            )?;
+            let name = attr.name.as_ref().unwrap_or(default_name);
+            let vis = attr
+                .vis
+                .as_ref()
+                .or(default_vis)
+                .map_or_else(|| quote! { pub }, ToTokens::to_token_stream);
+            let constness = fields.is_const().then(|| quote! { const });
+            let params = fields.params();
+            let body = fields.body(path.clone());
+
+            Ok(quote! {
+                #[inline]
+                #vis #constness fn #name(#( #params ),*) -> Self {
+                    #body
+                }
+            })
+        })
+        .collect()
+}
+
+/// Parses [`FieldAttribute`]s of the provided [`Fields`].
+pub(crate) fn parse_field_attrs(
+    fields: &Fields,
+    attr_name: &syn::Ident,
+) -> Result<Vec<Option<FieldAttribute>>> {
+    fields
+        .iter()
+        .map(|field| {
+            Ok(FieldAttribute::parse_attrs(&field.attrs, attr_name)?
+                .map(Spanning::into_inner))
+        })
+        .collect()
+}
+
+/// Fields of a struct (or an enum variant), constructed by a generated constructor.
+struct ConstructedFields<'a> {
+    /// Constructed [`Fields`].
+    fields: &'a Fields,
+
+    /// Initializations of the [`Fields`].
+    inits: Vec<Init<'a>>,
+}
+
+/// Initialization of a constructed field.
+enum Init<'a> {
+    /// Field is a constructor parameter of its exact type.
+    Param,
+
+    /// Field is a constructor parameter accepting anything convertible [`Into`] its type.
+    Into,
+
+    /// Field is initialized with its [`Default`] value.
+    Default,
+
+    /// Field is initialized with the provided expression.
+    Value(&'a syn::Expr),
+}
+
+impl<'a> ConstructedFields<'a> {
+    /// Creates new [`ConstructedFields`] out of the provided [`Fields`] and their parsed
+    /// [`FieldAttribute`]s.
+    ///
+    /// If `params` are specified, then only the listed fields are constructor parameters, while
+    /// all the other ones are initialized with their `#[new(value = ...)]` or [`Default`] value.
+    fn new(
+        fields: &'a Fields,
+        attrs: &'a [Option<FieldAttribute>],
+        params: Option<&[syn::Member]>,
+    ) -> Result<Self> {
+        if let Some(member) = params
+            .into_iter()
+            .flatten()
+            .find(|member| !fields.members().any(|m| m == **member))
+        {
+            return Err(syn::Error::new(
+                member.span(),
+                format!("no field `{}` to construct", member.to_token_stream()),
+            ));
+        }
+        if let Some((member, attr)) = params.into_iter().flatten().find_map(|member| {
+            let attr = fields
+                .members()
+                .zip(attrs)
+                .find_map(|(m, attr)| (m == *member).then_some(attr.as_ref()?))?;
+            match attr {
+                FieldAttribute::Default => Some((member, "#[new(default)]")),
+                FieldAttribute::Value(_) => Some((member, "#[new(value = ...)]")),
+                FieldAttribute::Into => None,
             }
+        }) {
+            return Err(syn::Error::new(
+                member.span(),
+                format!(
+                    "field `{}` has `{attr}` attribute and can't be a constructor parameter",
+                    member.to_token_stream(),
+                ),
+            ));
         }
+
+        let inits = fields
+            .members()
+            .zip(attrs)
+            .map(|(member, attr)| {
+                let is_param = params.map_or(true, |params| params.contains(&member));
+                match (attr, is_param) {
+                    (Some(FieldAttribute::Value(expr)), _) => Init::Value(expr),
+                    (Some(FieldAttribute::Default), _) | (_, false) => Init::Default,
+                    (Some(FieldAttribute::Into), true) => Init::Into,
+                    (None, true) => Init::Param,
+                }
+            })
+            .collect();
+        Ok(Self { fields, inits })
+    }
+
+    /// Returns the constructor parameter names of the [`Fields`].
+    fn idents(&self) -> impl Iterator<Item = syn::Ident> + 'a {
+        self.fields.iter().enumerate().map(|(n, field)| {
+            field
+                .ident
+                .clone()
+                .unwrap_or_else(|| format_ident!("__{n}"))
+        })
+    }
+
+    /// Indicates whether the constructor may be a `const fn`.
+    fn is_const(&self) -> bool {
+        self.fields
+            .iter()
+            .zip(&self.inits)
+            .all(|(field, init)| match init {
+                Init::Param => true,
+                Init::Into => false,
+                Init::Default => is_phantom_data(&field.ty),
+                Init::Value(expr) => is_const_expr(expr),
+            })
+    }
+
+    /// Returns the parameters of the constructor.
+    fn params(&self) -> Vec<TokenStream> {
+        self.fields
+            .iter()
+            .zip(self.idents())
+            .zip(&self.inits)
+            .filter_map(|((field, ident), init)| {
+                let ty = &field.ty;
+                match init {
+                    Init::Param => Some(quote! { #ident: #ty }),
+                    Init::Into => Some(quote! {
+                        #ident: impl derive_more::core::convert::Into<#ty>
+                    }),
+                    Init::Default | Init::Value(_) => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the expression constructing the value out of the constructor parameters, with
+    /// the provided `path` to the struct or the enum variant.
+    fn body(&self, path: TokenStream) -> TokenStream {
+        let values = self.fields.iter().zip(self.idents()).zip(&self.inits).map(
+            |((field, ident), init)| {
+                let ty = &field.ty;
+                match init {
+                    Init::Param => ident.to_token_stream(),
+                    Init::Into => quote! {
+                        derive_more::core::convert::Into::<#ty>::into(#ident)
+                    },
+                    Init::Default if is_phantom_data(ty) => {
+                        quote! { derive_more::core::marker::PhantomData }
+                    }
+                    Init::Default => quote! {
+                        <#ty as derive_more::core::default::Default>::default()
+                    },
+                    Init::Value(expr) => expr.to_token_stream(),
+                }
+            },
+        );
+        match self.fields {
+            Fields::Named(fields) => {
+                let idents = fields.named.iter().map(|f| &f.ident);
+                quote! { #path { #( #idents: #values ),* } }
+            }
+            Fields::Unnamed(_) => quote! { #path( #( #values ),* ) },
+            Fields::Unit => path,
+        }
+    }
+}
+
+/// Checks whether the provided [`syn::Type`] is a [`PhantomData`], which default value can be
+/// constructed in a `const` context.
+///
+/// [`PhantomData`]: core::marker::PhantomData
+pub(crate) fn is_phantom_data(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        syn::Type::Group(ty) => is_phantom_data(&ty.elem),
+        syn::Type::Paren(ty) => is_phantom_data(&ty.elem),
+        _ => false,
+    }
+}
+
+/// Checks whether the provided [`syn::Expr`] is a literal (optionally negated), so the
+/// constructor may remain a `const fn`.
+///
+/// Any other expression is considered non-`const`, as it may involve non-`const` paths,
+/// operators or calls, which cannot be detected reliably.
+fn is_const_expr(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(_) => true,
+        syn::Expr::Group(expr) => is_const_expr(&expr.expr),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(**expr, syn::Expr::Lit(_)),
+        _ => false,
+    }
+}
+
+/// Representation of a `#[new(...)]` attribute, placed on a struct, an enum or its variant,
+/// declaring a constructor.
+///
+/// ```rust,ignore
+/// #[new]
+/// #[new(name = "<name>", vis = "<visibility>", fields(<field>, ...))]
+/// ```
+#[derive(Default)]
+struct Attribute {
+    /// Name of the constructor.
+    name: Option<syn::Ident>,
+
+    /// Visibility of the constructor.
+    vis: Option<syn::Visibility>,
+
+    /// Fields being the constructor parameters, while all the other ones are defaulted.
+    fields: Option<Spanning<Vec<syn::Member>>>,
+}
+
+impl Attribute {
+    /// Parses all the `#[new(...)]` attributes declaring constructors from the provided
+    /// [`syn::Attribute`]s.
+    fn parse_all(
+        attrs: &[syn::Attribute],
+        attr_name: &syn::Ident,
+    ) -> Result<Vec<Spanning<Self>>> {
+        attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(attr_name))
+            .map(|attr| {
+                let out = match &attr.meta {
+                    syn::Meta::Path(_) => Self::default(),
+                    _ => attr.parse_args::<Self>()?,
+                };
+                Ok(Spanning::new(out, attr.span()))
+            })
+            .collect()
+    }
+}
+
+impl Parse for Attribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let lit = input.parse::<syn::LitStr>()?;
+                    let name = lit.parse::<syn::Ident>().map_err(|_| {
+                        syn::Error::new(lit.span(), "expected a method name")
+                    })?;
+                    if out.name.replace(name).is_some() {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "only single `name = \"...\"` argument is allowed here",
+                        ));
+                    }
+                }
+                "vis" => {
+                    input.parse::<token::Eq>()?;
+                    let lit = input.parse::<syn::LitStr>()?;
+                    let vis = lit.parse::<syn::Visibility>().map_err(|_| {
+                        syn::Error::new(lit.span(), "expected a visibility")
+                    })?;
+                    if out.vis.replace(vis).is_some() {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "only single `vis = \"...\"` argument is allowed here",
+                        ));
+                    }
+                }
+                "fields" => {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    let members =
+                        Punctuated::<syn::Member, token::Comma>::parse_terminated(
+                            &content,
+                        )?;
+                    let fields =
+                        Spanning::new(members.into_iter().collect(), ident.span());
+                    if out.fields.replace(fields).is_some() {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "only single `fields(...)` argument is allowed here",
+                        ));
+                    }
+                }
+                _ => return Err(syn::Error::new(
+                    ident.span(),
+                    "unknown argument, only `name`, `vis` and `fields` are allowed \
+                         here",
+                )),
+            }
+            if !input.is_empty() {
+                input.parse::<token::Comma>()?;
+            }
+        }
+        Ok(out)
     }
 }
 
-fn tuple_body(return_type: &Ident, fields: &[&Field]) -> (TokenStream, Vec<Ident>) {
-    let vars = &numbered_vars(fields.len(), "");
-    (quote! { #return_type(#(#vars),*) }, vars.clone())
+/// Representation of a `#[new(...)]` attribute, placed on a field.
+///
+/// ```rust,ignore
+/// #[new(default)]
+/// #[new(value = <expr>)]
+/// #[new(into)]
+/// ```
+pub(crate) enum FieldAttribute {
+    /// Field is initialized with its [`Default`] value, and isn't a constructor parameter.
+    Default,
+
+    /// Field is initialized with the provided expression, and isn't a constructor parameter.
+    Value(syn::Expr),
+
+    /// Constructor parameter accepts anything convertible [`Into`] the field type.
+    Into,
 }
 
-fn struct_body(return_type: &Ident, fields: &[&Field]) -> (TokenStream, Vec<Ident>) {
-    let field_names: &Vec<Ident> =
-        &field_idents(fields).iter().map(|f| (**f).clone()).collect();
-    let vars = field_names;
-    let ret_vars = field_names.clone();
-    (quote! { #return_type{#(#field_names: #vars),*} }, ret_vars)
+impl Parse for FieldAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let ident = input.parse::<syn::Ident>()?;
+        let out = match ident.to_string().as_str() {
+            "default" => Self::Default,
+            "into" => Self::Into,
+            "value" => {
+                input.parse::<token::Eq>()?;
+                Self::Value(input.parse()?)
+            }
+            _ => return Err(syn::Error::new(
+                ident.span(),
+                "unknown argument, only `default`, `value = <expr>` and `into` are \
+                     allowed here",
+            )),
+        };
+        if !input.is_empty() {
+            return Err(input.error("only single argument is allowed here"));
+        }
+        Ok(out)
+    }
 }
+
+impl attr::ParseMultiple for FieldAttribute {}
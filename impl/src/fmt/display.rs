@@ -196,10 +196,11 @@ fn expand_enum(
         },
     )?;
 
-    let body = match_arms
-        .is_empty()
-        .then(|| quote! { match *self {} })
-        .unwrap_or_else(|| quote! { match self { #match_arms } });
+    let body = if match_arms.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! { match self { #match_arms } }
+    };
 
     Ok((bounds, body))
 }
@@ -268,7 +269,7 @@ impl<'a> Expansion<'a> {
         // If `shared_attr` is a transparent call, then we consider it being absent.
         let has_shared_attr = self
             .shared_attr
-            .map_or(false, |a| a.transparent_call().is_none());
+            .is_some_and(|a| a.transparent_call().is_none());
 
         if !has_shared_attr
             || self
@@ -2,11 +2,14 @@
 //!
 //! [`fmt`]: std::fmt
 
+// Only parsing and generics detection are used by `#[derive(FromStr)]`.
+#![cfg_attr(not(any(feature = "debug", feature = "display")), allow(dead_code))]
+
 #[cfg(feature = "debug")]
 pub(crate) mod debug;
 #[cfg(feature = "display")]
 pub(crate) mod display;
-mod parsing;
+pub(crate) mod parsing;
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
@@ -293,7 +296,7 @@ impl FmtAttribute {
         fields.fmt_args_idents().filter_map(move |field_name| {
             (used_args.iter().any(|arg| field_name == arg)
                 && !self.args.iter().any(|arg| {
-                    arg.alias.as_ref().map_or(false, |(n, _)| n == &field_name)
+                    arg.alias.as_ref().is_some_and(|(n, _)| n == &field_name)
                 }))
             .then(|| quote! { #field_name = *#field_name })
         })
@@ -550,7 +553,7 @@ where
 }
 
 /// Extension of a [`syn::Type`] and a [`syn::Path`] allowing to travers its type parameters.
-trait ContainsGenericsExt {
+pub(crate) trait ContainsGenericsExt {
     /// Checks whether this definition contains any of the provided `type_params`.
     fn contains_generics(&self, type_params: &[&syn::Ident]) -> bool;
 }
@@ -569,7 +572,7 @@ impl ContainsGenericsExt for syn::Type {
                 }
 
                 if let Some(ident) = path.get_ident() {
-                    type_params.iter().any(|param| *param == ident)
+                    type_params.contains(&ident)
                 } else {
                     path.contains_generics(type_params)
                 }
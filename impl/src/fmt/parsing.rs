@@ -12,6 +12,19 @@ pub(crate) struct FormatString<'a> {
     pub(crate) formats: Vec<Format<'a>>,
 }
 
+/// Output of the [`pieces`] parser.
+#[cfg(feature = "from_str")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Piece<'a> {
+    /// Literal text with `{{` and `}}` escapes already resolved.
+    Text(&'a str),
+
+    /// Parsed [`format`].
+    ///
+    /// [`format`]: fn@format
+    Format(Format<'a>),
+}
+
 /// Output of the [`format`] parser.
 ///
 /// [`format`]: fn@format
@@ -207,6 +220,37 @@ pub(crate) fn format_string(input: &str) -> Option<FormatString<'_>> {
     input.is_empty().then_some(FormatString { formats })
 }
 
+/// Parses a `format_string` as defined in the [grammar spec][0] into a sequence
+/// of [`Piece`]s, preserving the literal text between [`Format`]s (unlike the
+/// [`format_string`] parser).
+///
+/// # Example
+///
+/// ```text
+/// ({x}, {y})
+/// {{{0}}}
+/// ```
+///
+/// [0]: std::fmt#syntax
+#[cfg(feature = "from_str")]
+pub(crate) fn pieces(input: &str) -> Option<Vec<Piece<'_>>> {
+    let mut input = input;
+    let pieces = iter::from_fn(|| {
+        let (curr, piece) = alt(&mut [
+            &mut map(str("{{"), |i| (i, Piece::Text("{"))),
+            &mut map(str("}}"), |i| (i, Piece::Text("}"))),
+            &mut map(format, |(i, format)| (i, Piece::Format(format))),
+            &mut map(text, |(i, text)| (i, Piece::Text(text))),
+        ])(input)?;
+        input = curr;
+        Some(piece)
+    })
+    .collect();
+
+    // Should consume all tokens for a successful parse.
+    input.is_empty().then_some(pieces)
+}
+
 /// Parses a `maybe_format` as defined in the [grammar spec][0].
 ///
 /// # Grammar
@@ -280,7 +324,7 @@ pub(crate) fn format(input: &str) -> Option<(LeftToParse<'_>, Format<'_>)> {
 /// ```
 ///
 /// [0]: std::fmt#syntax
-fn argument(input: &str) -> Option<(LeftToParse<'_>, Argument)> {
+fn argument(input: &str) -> Option<(LeftToParse<'_>, Argument<'_>)> {
     alt(&mut [
         &mut map(identifier, |(i, ident)| (i, Argument::Identifier(ident))),
         &mut map(integer, |(i, int)| (i, Argument::Integer(int))),
@@ -522,7 +566,7 @@ fn identifier(input: &str) -> Option<(LeftToParse<'_>, Identifier<'_>)> {
             ),
             &mut and_then(char('_'), take_while1(check_char(XID::is_xid_continue))),
         ]),
-        |(i, _)| (i, &input[..(input.as_bytes().len() - i.as_bytes().len())]),
+        |(i, _)| (i, &input[..(input.len() - i.len())]),
     )(input)
 }
 
@@ -614,10 +658,7 @@ fn take_while0(
         while let Some(step) = parser(cur) {
             cur = step;
         }
-        (
-            cur,
-            &input[..(input.as_bytes().len() - cur.as_bytes().len())],
-        )
+        (cur, &input[..(input.len() - cur.len())])
     }
 }
 
@@ -631,10 +672,7 @@ fn take_while1(
         while let Some(step) = parser(cur) {
             cur = step;
         }
-        Some((
-            cur,
-            &input[..(input.as_bytes().len() - cur.as_bytes().len())],
-        ))
+        Some((cur, &input[..(input.len() - cur.len())]))
     }
 }
 
@@ -662,16 +700,13 @@ fn take_until1(
             cur = b;
         }
 
-        Some((
-            cur,
-            &input[..(input.as_bytes().len() - cur.as_bytes().len())],
-        ))
+        Some((cur, &input[..(input.len() - cur.len())]))
     }
 }
 
 /// Checks whether `input` starts with `s`.
 fn str(s: &str) -> impl FnMut(&str) -> Option<LeftToParse<'_>> + '_ {
-    move |input| input.starts_with(s).then(|| &input[s.as_bytes().len()..])
+    move |input| input.starts_with(s).then(|| &input[s.len()..])
 }
 
 /// Checks whether `input` starts with `c`.
@@ -1308,4 +1343,39 @@ mod tests {
         assert_eq!(format_string("{:par}"), None);
         assert_eq!(format_string("{⚙️}"), None);
     }
+
+    #[cfg(feature = "from_str")]
+    #[test]
+    fn pieces() {
+        assert_eq!(super::pieces(""), Some(vec![]));
+        assert_eq!(
+            super::pieces("({x}, {})"),
+            Some(vec![
+                Piece::Text("("),
+                Piece::Format(Format {
+                    arg: Some(Argument::Identifier("x")),
+                    spec: None,
+                }),
+                Piece::Text(", "),
+                Piece::Format(Format {
+                    arg: None,
+                    spec: None,
+                }),
+                Piece::Text(")"),
+            ]),
+        );
+        assert_eq!(
+            super::pieces("{{{0}}}"),
+            Some(vec![
+                Piece::Text("{"),
+                Piece::Format(Format {
+                    arg: Some(Argument::Integer(0)),
+                    spec: None,
+                }),
+                Piece::Text("}"),
+            ]),
+        );
+        assert_eq!(super::pieces("{"), None);
+        assert_eq!(super::pieces("a}b"), None);
+    }
 }
@@ -1,112 +1,868 @@
-use crate::utils::{DeriveType, HashMap};
-use crate::utils::{SingleFieldData, State};
+use crate::fmt::{
+    parsing::{self, Argument, Piece},
+    ContainsGenericsExt as _,
+};
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    Either, HashMap, Spanning,
+};
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{parse::Result, DeriveInput};
+use quote::{format_ident, quote, ToTokens as _};
+use std::iter;
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream, Result},
+    parse_quote,
+    spanned::Spanned as _,
+    token, DeriveInput,
+};
 
 /// Provides the hook to expand `#[derive(FromStr)]` into an implementation of `FromStr`
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
-    let state = State::new(input, trait_name, trait_name.to_lowercase())?;
-
-    if state.derive_type == DeriveType::Enum {
-        Ok(enum_from(input, state, trait_name))
-    } else {
-        Ok(struct_from(&state, trait_name))
+    match &input.data {
+        syn::Data::Enum(data) => enum_from(input, data, trait_name),
+        syn::Data::Struct(data) => struct_from(input, data, trait_name),
+        syn::Data::Union(data) => Err(syn::Error::new(
+            data.union_token.span(),
+            format!("`{trait_name}` cannot be derived for unions"),
+        )),
     }
 }
 
-pub fn struct_from(state: &State, trait_name: &'static str) -> TokenStream {
-    // We cannot set defaults for fields, once we do we can remove this check
-    if state.fields.len() != 1 || state.enabled_fields().len() != 1 {
-        panic_one_field(trait_name);
-    }
+fn struct_from(
+    input: &DeriveInput,
+    data: &syn::DataStruct,
+    trait_name: &'static str,
+) -> Result<TokenStream> {
+    let attr_name = format_ident!("from_str");
+    let attr = StructAttribute::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner)
+        .unwrap_or_default();
 
-    let single_field_data = state.assert_single_enabled_field();
-    let SingleFieldData {
-        input_type,
-        field_type,
-        trait_path,
-        casted_trait,
-        impl_generics,
-        ty_generics,
-        where_clause,
-        ..
-    } = single_field_data.clone();
+    let input_type = &input.ident;
+    let input_type_name = input_type.to_string();
+    let generics = with_field_bounds(&input.generics, data.fields.iter());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let initializers = [quote! { #casted_trait::from_str(src)? }];
-    let body = single_field_data.initializer(&initializers);
+    let (error_ty, body) = if let Some(fmt) = &attr.format {
+        if let Some(validate) = &attr.validate {
+            return Err(syn::Error::new(
+                validate.0.span(),
+                "`validate` cannot be combined with a format, as it's allowed only for \
+                 single-field structs",
+            ));
+        }
+        let parser = fmt.parser(&data.fields, quote! { Self })?;
+        let expected = fmt.0.value();
+        let error = quote! {
+            derive_more::FromStrError::new(#input_type_name, &[#expected])
+        };
+        let error = attr
+            .error
+            .as_ref()
+            .map_or(error.clone(), |err| err.convert(error));
+        (
+            quote! { derive_more::FromStrError },
+            quote! { #parser.ok_or_else(|| #error) },
+        )
+    } else {
+        let mut fields = data.fields.iter();
+        let (Some(field), None) = (fields.next(), fields.next()) else {
+            return Err(syn::Error::new(
+                data.fields.span(),
+                format!(
+                    "Only structs with one field can derive({trait_name}), unless a \
+                     `#[{attr_name}(\"...\")]` format is specified",
+                ),
+            ));
+        };
+        let ty = &field.ty;
+        let map_err = attr.error.as_ref().map(attr::Error::map_err);
+        let validate = attr.validate.as_ref().map(|v| v.call(quote! { value }));
+        let value = match &field.ident {
+            Some(ident) => quote! { Self { #ident: value } },
+            None => quote! { Self(value) },
+        };
+        (
+            quote! { <#ty as derive_more::FromStr>::Err },
+            quote! {
+                let value = <#ty as derive_more::FromStr>::from_str(src)#map_err?;
+                #validate
+                derive_more::core::result::Result::Ok(#value)
+            },
+        )
+    };
+    let error_ty = attr
+        .error
+        .as_ref()
+        .map_or(error_ty, |err| err.ty.to_token_stream());
 
-    quote! {
+    Ok(quote! {
         #[automatically_derived]
-        impl #impl_generics #trait_path for #input_type #ty_generics #where_clause {
-            type Err = <#field_type as #trait_path>::Err;
+        impl #impl_generics derive_more::FromStr for #input_type #ty_generics #where_clause {
+            type Err = #error_ty;
 
             #[inline]
             fn from_str(src: &str) -> derive_more::core::result::Result<Self, Self::Err> {
-                derive_more::core::result::Result::Ok(#body)
+                #body
+            }
+        }
+    })
+}
+
+/// Representation of a [`FromStr`] derive macro struct attribute.
+///
+/// ```rust,ignore
+/// #[from_str("<fmt-literal>")]
+/// #[from_str(error(<ty>))]
+/// #[from_str(error(<ty>, <map-fn>))]
+/// #[from_str(validate = <fn-path>)]
+/// ```
+///
+/// [`FromStr`]: std::str::FromStr
+#[derive(Default)]
+struct StructAttribute {
+    /// Format to parse the input string by.
+    format: Option<FormatAttribute>,
+
+    /// Custom error type to return, along with the function mapping the original error
+    /// into it.
+    error: Option<attr::Error>,
+
+    /// Function validating the parsed value of a single field.
+    validate: Option<attr::Validate>,
+}
+
+impl Parse for StructAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            if input.peek(syn::LitStr) {
+                let fmt = input.parse::<FormatAttribute>()?;
+                let span = fmt.0.span();
+                if out.format.replace(fmt).is_some() {
+                    return Err(syn::Error::new(
+                        span,
+                        "only single format literal is allowed here",
+                    ));
+                }
+            } else {
+                let ident = input.fork().parse::<syn::Ident>()?;
+                match ident.to_string().as_str() {
+                    "error" => set_once(&mut out.error, input.parse()?, &ident)?,
+                    "validate" => set_once(&mut out.validate, input.parse()?, &ident)?,
+                    _ => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "unknown argument, only a format literal, `error` and \
+                             `validate` are allowed here",
+                        ))
+                    }
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<token::Comma>()?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl attr::ParseMultiple for StructAttribute {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        name: &syn::Ident,
+    ) -> Result<Spanning<Self>> {
+        let Spanning {
+            span: prev_span,
+            item: mut prev,
+        } = prev;
+        let Spanning {
+            span: new_span,
+            item: new,
+        } = new;
+
+        for (is_duplicate, arg) in [
+            (prev.format.is_some() && new.format.is_some(), "\"...\""),
+            (prev.error.is_some() && new.error.is_some(), "error(...)"),
+            (
+                prev.validate.is_some() && new.validate.is_some(),
+                "validate = ...",
+            ),
+        ] {
+            if is_duplicate {
+                return Err(syn::Error::new(
+                    new_span,
+                    format!("only single `#[{name}({arg})]` is allowed here"),
+                ));
+            }
+        }
+        prev.format = prev.format.or(new.format);
+        prev.error = prev.error.or(new.error);
+        prev.validate = prev.validate.or(new.validate);
+
+        Ok(Spanning::new(
+            prev,
+            prev_span.join(new_span).unwrap_or(prev_span),
+        ))
+    }
+}
+
+/// Representation of a [`FromStr`] derive macro struct or enum variant attribute,
+/// specifying the format to parse the input string by.
+///
+/// ```rust,ignore
+/// #[from_str("<fmt-literal>")]
+/// ```
+///
+/// [`FromStr`]: std::str::FromStr
+struct FormatAttribute(syn::LitStr);
+
+impl Parse for FormatAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        input.parse().map(Self)
+    }
+}
+
+impl attr::ParseMultiple for FormatAttribute {}
+
+impl FormatAttribute {
+    /// Generates an expression parsing the `src` input string by this format into an
+    /// [`Option`] of the provided `fields` passed to the `constructor`.
+    ///
+    /// Every placeholder is parsed with the [`FromStr`] implementation of its field,
+    /// while the literal text between them is matched exactly. Input of a placeholder
+    /// lasts until the first occurrence of the literal text following it.
+    ///
+    /// [`FromStr`]: std::str::FromStr
+    fn parser(
+        &self,
+        fields: &syn::Fields,
+        constructor: TokenStream,
+    ) -> Result<TokenStream> {
+        let fmt = self.0.value();
+        let span = self.0.span();
+        let pieces = parsing::pieces(&fmt)
+            .ok_or_else(|| syn::Error::new(span, "invalid format string"))?;
+
+        // Literal text before the first placeholder.
+        let mut prefix = String::new();
+        // Field indices of the placeholders along with the literal text following them.
+        let mut placeholders: Vec<(usize, String)> = vec![];
+        let mut next_positional = 0;
+        for piece in pieces {
+            let format = match piece {
+                Piece::Text(text) => {
+                    placeholders
+                        .last_mut()
+                        .map_or(&mut prefix, |(_, t)| t)
+                        .push_str(text);
+                    continue;
+                }
+                Piece::Format(format) => format,
+            };
+            if format.spec.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "formatting parameters are not supported in `FromStr` format",
+                ));
+            }
+
+            let index = match (format.arg, fields) {
+                (Some(Argument::Identifier(name)), syn::Fields::Named(_)) => fields
+                    .iter()
+                    .position(|f| f.ident.as_ref().is_some_and(|i| i.unraw() == name))
+                    .ok_or_else(|| {
+                        syn::Error::new(span, format!("field `{name}` doesn't exist"))
+                    })?,
+                (Some(Argument::Integer(index)), syn::Fields::Unnamed(_)) => index,
+                (None, syn::Fields::Unnamed(_)) => {
+                    next_positional += 1;
+                    next_positional - 1
+                }
+                (_, syn::Fields::Named(_)) => return Err(syn::Error::new(
+                    span,
+                    "named fields should be referred by their names, like `{field}`",
+                )),
+                (_, _) => return Err(syn::Error::new(
+                    span,
+                    "unnamed fields should be referred by their positions, like `{}` \
+                         or `{0}`",
+                )),
+            };
+            if index >= fields.len() {
+                return Err(syn::Error::new(
+                    span,
+                    format!("field `{index}` doesn't exist"),
+                ));
             }
+            if placeholders.iter().any(|(i, _)| *i == index) {
+                return Err(syn::Error::new(
+                    span,
+                    "every field should be referred only once in `FromStr` format",
+                ));
+            }
+            if placeholders.last().is_some_and(|(_, text)| text.is_empty()) {
+                return Err(syn::Error::new(
+                    span,
+                    "placeholders should be separated by literal text in `FromStr` format",
+                ));
+            }
+            placeholders.push((index, String::new()));
+        }
+        if let Some(field) = fields
+            .iter()
+            .enumerate()
+            .find_map(|(n, f)| placeholders.iter().all(|(i, _)| *i != n).then_some(f))
+        {
+            return Err(syn::Error::new(
+                field.span(),
+                "field is missing in `FromStr` format",
+            ));
+        }
+
+        let rest = format_ident!("__derive_more_rest");
+        let mut steps = vec![];
+        if !prefix.is_empty() {
+            steps.push(quote! { let #rest = #rest.strip_prefix(#prefix)?; });
+        }
+        for (n, (index, text)) in placeholders.iter().enumerate() {
+            let var = format_ident!("__{index}");
+            steps.push(if n + 1 < placeholders.len() {
+                quote! { let (#var, #rest) = #rest.split_once(#text)?; }
+            } else if text.is_empty() {
+                quote! { let #var = #rest; }
+            } else {
+                quote! { let #var = #rest.strip_suffix(#text)?; }
+            });
         }
+        if placeholders.is_empty() {
+            steps.push(quote! {
+                if !#rest.is_empty() {
+                    return derive_more::core::option::Option::None;
+                }
+            });
+        }
+
+        let values = fields.iter().enumerate().map(|(n, field)| {
+            let var = format_ident!("__{n}");
+            let ty = &field.ty;
+            quote! { <#ty as derive_more::core::str::FromStr>::from_str(#var).ok()? }
+        });
+        let value = match fields {
+            syn::Fields::Named(_) => {
+                let idents = fields.iter().map(|f| &f.ident);
+                quote! { #constructor { #( #idents: #values ),* } }
+            }
+            syn::Fields::Unnamed(_) => quote! { #constructor( #( #values ),* ) },
+            syn::Fields::Unit => constructor,
+        };
+
+        Ok(quote! {
+            (|| {
+                let #rest = src;
+                #( #steps )*
+                derive_more::core::option::Option::Some(#value)
+            })()
+        })
+    }
+}
+
+/// Extends the provided `generics` with [`FromStr`] bounds for the provided `fields`,
+/// whose types contain any of its type parameters.
+///
+/// [`FromStr`]: std::str::FromStr
+fn with_field_bounds<'a>(
+    generics: &syn::Generics,
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+) -> syn::Generics {
+    let type_params: Vec<_> = generics.type_params().map(|p| &p.ident).collect();
+    let bounds: Vec<syn::WherePredicate> = fields
+        .into_iter()
+        .map(|f| &f.ty)
+        .filter(|ty| ty.contains_generics(&type_params))
+        .map(|ty| parse_quote! { #ty: derive_more::core::str::FromStr })
+        .collect();
+
+    let mut generics = generics.clone();
+    if !bounds.is_empty() {
+        generics.make_where_clause().predicates.extend(bounds);
+    }
+    generics
+}
+
+/// Representation of a [`FromStr`] derive macro enum attribute.
+///
+/// ```rust,ignore
+/// #[from_str(rename_all = "<casing>")]
+/// #[from_str(case = "sensitive" | "insensitive" | "ascii_insensitive")]
+/// #[from_str(try_each)]
+/// #[from_str(error(<ty>))]
+/// #[from_str(error(<ty>, <map-fn>))]
+/// ```
+///
+/// [`FromStr`]: std::str::FromStr
+#[derive(Default)]
+pub(crate) struct EnumAttribute {
+    /// Casing to convert the variant identifiers into.
+    rename_all: Option<attr::RenameAll>,
+
+    /// Mode of matching the input against the variant names.
+    case: Option<Case>,
+
+    /// Indicator whether single-field variants should be parsed by trying the
+    /// [`FromStr`] implementation of their field.
+    ///
+    /// [`FromStr`]: std::str::FromStr
+    try_each: Option<syn::Ident>,
+
+    /// Custom error type to return, along with the function mapping the original error
+    /// into it.
+    error: Option<attr::Error>,
+}
+
+impl Parse for EnumAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.fork().parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "rename_all" => set_once(&mut out.rename_all, input.parse()?, &ident)?,
+                "case" => set_once(&mut out.case, input.parse()?, &ident)?,
+                "try_each" => set_once(&mut out.try_each, input.parse()?, &ident)?,
+                "error" => set_once(&mut out.error, input.parse()?, &ident)?,
+                _ => return Err(syn::Error::new(
+                    ident.span(),
+                    "unknown argument, only `rename_all`, `case`, `try_each` and `error` \
+                     are allowed here",
+                )),
+            }
+            if !input.is_empty() {
+                input.parse::<token::Comma>()?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl attr::ParseMultiple for EnumAttribute {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        name: &syn::Ident,
+    ) -> Result<Spanning<Self>> {
+        let Spanning {
+            span: prev_span,
+            item: mut prev,
+        } = prev;
+        let Spanning {
+            span: new_span,
+            item: new,
+        } = new;
+
+        for (is_duplicate, arg) in [
+            (
+                prev.rename_all.is_some() && new.rename_all.is_some(),
+                "rename_all = \"...\"",
+            ),
+            (prev.case.is_some() && new.case.is_some(), "case = \"...\""),
+            (prev.error.is_some() && new.error.is_some(), "error(...)"),
+        ] {
+            if is_duplicate {
+                return Err(syn::Error::new(
+                    new_span,
+                    format!("only single `#[{name}({arg})]` is allowed here"),
+                ));
+            }
+        }
+        prev.rename_all = prev.rename_all.or(new.rename_all);
+        prev.case = prev.case.or(new.case);
+        prev.try_each = prev.try_each.or(new.try_each);
+        prev.error = prev.error.or(new.error);
+
+        Ok(Spanning::new(
+            prev,
+            prev_span.join(new_span).unwrap_or(prev_span),
+        ))
+    }
+}
+
+impl EnumAttribute {
+    /// Returns the name of the variant with the provided `ident`, unless it's renamed
+    /// explicitly: the `ident` converted into the `rename_all` casing, if any.
+    pub(crate) fn default_name(&self, ident: &syn::Ident) -> String {
+        self.rename_all
+            .map_or_else(|| ident.to_string(), |case| case.convert(ident))
+    }
+}
+
+/// Parses the name of the provided fieldless enum `variant`, specified explicitly by
+/// its `#[from_str(rename = "...")]` attribute or by its `#[from_str("...")]` format
+/// without placeholders.
+#[cfg(feature = "variants")]
+pub(crate) fn explicit_variant_name(variant: &syn::Variant) -> Result<Option<String>> {
+    let attr = <Either<FormatAttribute, VariantAttribute>>::parse_attrs(
+        &variant.attrs,
+        &format_ident!("from_str"),
+    )?
+    .map(Spanning::into_inner);
+    Ok(match attr {
+        Some(Either::Left(fmt)) => literal_name(&fmt.0),
+        Some(Either::Right(attr)) => attr.rename.map(|lit| lit.value()),
+        None => None,
+    })
+}
+
+/// Returns the literal text of the provided format string `lit` with its `{{` and `}}` escapes
+/// resolved, if it has no placeholders.
+#[cfg(feature = "variants")]
+pub(crate) fn literal_name(lit: &syn::LitStr) -> Option<String> {
+    parsing::pieces(&lit.value())?
+        .into_iter()
+        .map(|piece| match piece {
+            Piece::Text(text) => Some(text),
+            Piece::Format(_) => None,
+        })
+        .collect()
+}
+
+/// Mode of matching the input string against the names of enum variants.
+///
+/// ```rust,ignore
+/// case = "sensitive"
+/// case = "insensitive"
+/// case = "ascii_insensitive"
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Case {
+    /// Names are matched exactly.
+    Sensitive,
+
+    /// Names are matched regardless of their Unicode case.
+    Insensitive,
+
+    /// Names are matched regardless of their ASCII case.
+    AsciiInsensitive,
+}
+
+impl Case {
+    /// Returns the key that names are compared by in this [`Case`] mode.
+    fn key(self, name: &str) -> String {
+        match self {
+            Self::Sensitive => name.to_owned(),
+            // Lowercasing per `char` (instead of `str::to_lowercase()`), to match the
+            // runtime comparison of `derive_more::__private::eq_ignore_case()`.
+            Self::Insensitive => name.chars().flat_map(char::to_lowercase).collect(),
+            Self::AsciiInsensitive => name.to_ascii_lowercase(),
+        }
+    }
+}
+
+impl Parse for Case {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let ident = input.parse::<syn::Ident>()?;
+        if ident != "case" {
+            return Err(syn::Error::new(ident.span(), "only `case` allowed here"));
+        }
+        input.parse::<token::Eq>()?;
+
+        let mode = input.parse::<syn::LitStr>()?;
+        match mode.value().as_str() {
+            "sensitive" => Ok(Self::Sensitive),
+            "insensitive" => Ok(Self::Insensitive),
+            "ascii_insensitive" => Ok(Self::AsciiInsensitive),
+            _ => Err(syn::Error::new(
+                mode.span(),
+                "unexpected case mode, expected one of: \
+                 `sensitive`, `insensitive`, `ascii_insensitive`",
+            )),
+        }
+    }
+}
+
+/// Representation of a [`FromStr`] derive macro enum variant attribute.
+///
+/// ```rust,ignore
+/// #[from_str(rename = "<name>")]
+/// #[from_str(alias = "<name>")]
+/// #[from_str(rename = "<name>", alias = "<name>", alias = "<name>")]
+/// ```
+///
+/// [`FromStr`]: std::str::FromStr
+#[derive(Default)]
+struct VariantAttribute {
+    /// Name to match instead of the variant identifier.
+    rename: Option<syn::LitStr>,
+
+    /// Additional names to match along with the main one.
+    aliases: Vec<syn::LitStr>,
+}
+
+impl Parse for VariantAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            input.parse::<token::Eq>()?;
+            let name = input.parse::<syn::LitStr>()?;
+            match ident.to_string().as_str() {
+                "rename" => set_once(&mut out.rename, name, &ident)?,
+                "alias" => out.aliases.push(name),
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "unknown argument, only `rename` and `alias` are allowed here",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<token::Comma>()?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl attr::ParseMultiple for VariantAttribute {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        name: &syn::Ident,
+    ) -> Result<Spanning<Self>> {
+        let Spanning {
+            span: prev_span,
+            item: mut prev,
+        } = prev;
+        let Spanning {
+            span: new_span,
+            item: new,
+        } = new;
+
+        if let Some(rename) = new.rename {
+            if prev.rename.replace(rename).is_some() {
+                return Err(syn::Error::new(
+                    new_span,
+                    format!(
+                        "only single `#[{name}(rename = \"...\")]` is allowed here"
+                    ),
+                ));
+            }
+        }
+        prev.aliases.extend(new.aliases);
+
+        Ok(Spanning::new(
+            prev,
+            prev_span.join(new_span).unwrap_or(prev_span),
+        ))
     }
 }
 
 fn enum_from(
     input: &DeriveInput,
-    state: State,
+    data: &syn::DataEnum,
     trait_name: &'static str,
-) -> TokenStream {
-    let mut variants_caseinsensitive = HashMap::default();
-    for variant_state in state.enabled_variant_data().variant_states {
-        let variant = variant_state.variant.unwrap();
+) -> Result<TokenStream> {
+    let attr_name = format_ident!("from_str");
+    let attr = EnumAttribute::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner)
+        .unwrap_or_default();
+
+    let mut names = HashMap::default();
+    let mut spellings = vec![];
+    let mut parsers = vec![];
+    let mut parsed_fields = vec![];
+    // Accepted string representations, reported in the error.
+    let mut expected = vec![];
+    for variant in &data.variants {
+        let variant_attr =
+            match <Either<FormatAttribute, VariantAttribute>>::parse_attrs(
+                &variant.attrs,
+                &attr_name,
+            )?
+            .map(Spanning::into_inner)
+            {
+                Some(Either::Left(fmt)) => {
+                    let ident = &variant.ident;
+                    parsers.push(fmt.parser(&variant.fields, quote! { Self::#ident })?);
+                    expected.push(fmt.0.value());
+                    parsed_fields.extend(&variant.fields);
+                    continue;
+                }
+                Some(Either::Right(attr)) => attr,
+                None => VariantAttribute::default(),
+            };
         if !variant.fields.is_empty() {
-            panic!("Only enums with no fields can derive({trait_name})")
+            if attr.try_each.is_none() || variant.fields.len() != 1 {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    format!(
+                        "Only enums with no fields can derive({trait_name}), unless a \
+                         `#[{attr_name}(\"...\")]` format is specified for the variant, \
+                         or `#[{attr_name}(try_each)]` is specified for the enum with \
+                         single-field variants",
+                    ),
+                ));
+            }
+            if let Some(lit) = variant_attr
+                .rename
+                .iter()
+                .chain(&variant_attr.aliases)
+                .next()
+            {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "`rename` and `alias` are allowed only for variants with no fields",
+                ));
+            }
+
+            let ident = &variant.ident;
+            let field = variant.fields.iter().next().unwrap();
+            let ty = &field.ty;
+            let constructor = match &field.ident {
+                Some(name) => quote! { |#name| Self::#ident { #name } },
+                None => quote! { Self::#ident },
+            };
+            parsers.push(quote! {
+                <#ty as derive_more::core::str::FromStr>::from_str(src)
+                    .ok()
+                    .map(#constructor)
+            });
+            parsed_fields.push(field);
+            continue;
         }
+        let name = variant_attr
+            .rename
+            .map_or_else(|| attr.default_name(&variant.ident), |lit| lit.value());
 
-        variants_caseinsensitive
-            .entry(variant.ident.to_string().to_lowercase())
-            .or_insert_with(Vec::new)
-            .push(variant.ident.clone());
+        let aliases = variant_attr
+            .aliases
+            .iter()
+            .map(|lit| (lit.value(), lit.span()));
+        for (spelling, span) in iter::once((name, variant.ident.span())).chain(aliases)
+        {
+            match names.insert(spelling.clone(), variant.ident.clone()) {
+                Some(other) if other != variant.ident => {
+                    return Err(syn::Error::new(
+                        span,
+                        format!(
+                            "`{spelling}` is already used by the `{other}` variant"
+                        ),
+                    ));
+                }
+                Some(_) => continue,
+                None => {
+                    expected.push(spelling.clone());
+                    spellings.push((spelling, span, &variant.ident));
+                }
+            }
+        }
     }
 
     let input_type = &input.ident;
     let input_type_name = input_type.to_string();
+    let generics = with_field_bounds(&input.generics, parsed_fields);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let mut cases = vec![];
-
-    // if a case insensitive match is unique match do that
-    // otherwise do a case sensitive match
-    for (ref canonical, ref variants) in variants_caseinsensitive {
-        if variants.len() == 1 {
-            let variant = &variants[0];
-            cases.push(quote! {
-                #canonical => #input_type::#variant,
-            })
-        } else {
-            for variant in variants {
-                let variant_str = variant.to_string();
-                cases.push(quote! {
-                    #canonical if(src == #variant_str) => #input_type::#variant,
-                })
+    let eq_ignore_case = quote! { derive_more::__private::eq_ignore_case };
+    let mut conditions = vec![];
+    match attr.case {
+        None => {
+            let mut variants_caseinsensitive: Vec<(String, Vec<_>)> = vec![];
+            for (spelling, _, variant) in spellings {
+                let canonical = Case::Insensitive.key(&spelling);
+                match variants_caseinsensitive
+                    .iter_mut()
+                    .find(|(c, _)| *c == canonical)
+                {
+                    Some((_, variants)) => variants.push((spelling, variant)),
+                    None => variants_caseinsensitive
+                        .push((canonical, vec![(spelling, variant)])),
+                }
+            }
+
+            // if a case insensitive match is unique match do that
+            // otherwise do a case sensitive match
+            for (canonical, variants) in variants_caseinsensitive {
+                if variants.iter().all(|(_, ident)| *ident == variants[0].1) {
+                    conditions.push((
+                        quote! { #eq_ignore_case(src, #canonical) },
+                        variants[0].1,
+                    ));
+                } else {
+                    for (spelling, variant) in variants {
+                        conditions.push((quote! { src == #spelling }, variant));
+                    }
+                }
+            }
+        }
+        Some(case) => {
+            let mut keys = HashMap::default();
+            for (spelling, span, variant) in spellings {
+                let key = case.key(&spelling);
+                match keys.insert(key.clone(), (spelling.clone(), variant)) {
+                    Some((_, other)) if other == variant => {}
+                    Some((other_spelling, other)) => {
+                        return Err(syn::Error::new(
+                            span,
+                            format!(
+                                "`{spelling}` of the `{variant}` variant is ambiguous with \
+                                 `{other_spelling}` of the `{other}` variant in this case \
+                                 mode",
+                            ),
+                        ));
+                    }
+                    None => conditions.push((
+                        match case {
+                            Case::Sensitive => quote! { src == #spelling },
+                            Case::Insensitive => quote! { #eq_ignore_case(src, #key) },
+                            Case::AsciiInsensitive => {
+                                quote! { src.eq_ignore_ascii_case(#spelling) }
+                            }
+                        },
+                        variant,
+                    )),
+                }
             }
         }
     }
+    let (conditions, variants): (Vec<_>, Vec<_>) = conditions.into_iter().unzip();
 
-    let trait_path = state.trait_path;
+    let trait_path = quote! { derive_more::FromStr };
+    let error = quote! {
+        derive_more::FromStrError::new(#input_type_name, &[#( #expected ),*])
+    };
+    let (error_ty, error) = match &attr.error {
+        Some(err) => (err.ty.to_token_stream(), err.convert(error)),
+        None => (quote! { derive_more::FromStrError }, error),
+    };
 
-    quote! {
-        impl #trait_path for #input_type {
-            type Err = derive_more::FromStrError;
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #input_type #ty_generics #where_clause {
+            type Err = #error_ty;
 
             #[inline]
             fn from_str(src: &str) -> derive_more::core::result::Result<Self, Self::Err> {
-                Ok(match src.to_lowercase().as_str() {
-                    #(#cases)*
-                    _ => return Err(derive_more::FromStrError::new(#input_type_name)),
+                derive_more::core::result::Result::Ok(#( if #conditions {
+                    #input_type::#variants
+                } else )* #( if let derive_more::core::option::Option::Some(parsed) = #parsers {
+                    parsed
+                } else )* {
+                    return derive_more::core::result::Result::Err(#error);
                 })
             }
         }
-    }
+    })
 }
 
-fn panic_one_field(trait_name: &str) -> ! {
-    panic!("Only structs with one field can derive({trait_name})")
+/// Sets the provided `value` into the `slot`, erroring if it has been set already.
+fn set_once<T>(slot: &mut Option<T>, value: T, arg: &syn::Ident) -> Result<()> {
+    if slot.replace(value).is_some() {
+        return Err(syn::Error::new(
+            arg.span(),
+            format!("only single `{arg}` argument is allowed here"),
+        ));
+    }
+    Ok(())
 }
@@ -0,0 +1,83 @@
+//! Implementation of a [`Getters`] derive macro.
+
+use crate::utils::{AttrParams, RefType, State};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned as _, Data, DeriveInput, Fields, Result};
+
+/// Expands a [`Getters`] derive macro.
+pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            format!("`{trait_name}` can only be derived for structs"),
+        ));
+    };
+    if matches!(data.fields, Fields::Unnamed(_)) {
+        return Err(syn::Error::new(
+            data.fields.span(),
+            format!("`{trait_name}` can only be derived for structs with named fields"),
+        ));
+    }
+
+    let state = State::with_attr_params(
+        input,
+        trait_name,
+        "getter".into(),
+        AttrParams {
+            enum_: vec![],
+            variant: vec![],
+            struct_: vec![],
+            field: vec!["skip", "copy", "mut", "rename"],
+        },
+    )?;
+    let data = state.enabled_fields_data();
+
+    let vis = &input.vis;
+    let mut getters = vec![];
+    for (field, info) in data.fields.iter().zip(&data.infos) {
+        let field_ident = field.ident.as_ref().unwrap();
+        let field_ty = &field.ty;
+        let name = info.info.rename.as_ref().unwrap_or(field_ident);
+        let docs = field.attrs.iter().filter(|a| a.path().is_ident("doc"));
+
+        let mut ref_types = vec![if info.info.owned.unwrap_or_default() {
+            RefType::No
+        } else {
+            RefType::Ref
+        }];
+        if info.info.ref_mut.unwrap_or_default() {
+            ref_types.push(RefType::Mut);
+        }
+        for ref_type in ref_types {
+            let fn_name = match ref_type {
+                RefType::Mut => format_ident!("{name}_mut", span = name.span()),
+                RefType::No | RefType::Ref => name.clone(),
+            };
+            let reference = ref_type.reference();
+            let receiver = match ref_type {
+                RefType::Mut => quote! { &mut self },
+                RefType::No | RefType::Ref => quote! { &self },
+            };
+            let docs = docs.clone();
+            getters.push(quote! {
+                #( #docs )*
+                #[inline]
+                #[must_use]
+                #vis fn #fn_name(#receiver) -> #reference #field_ty {
+                    #reference self.#field_ident
+                }
+            });
+        }
+    }
+
+    let struct_name = &input.ident;
+    let (imp_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #imp_generics #struct_name #type_generics #where_clause {
+            #( #getters )*
+        }
+    })
+}
@@ -27,10 +27,7 @@ pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStr
 
     let data = match &input.data {
         syn::Data::Struct(data) => Ok(data),
-        syn::Data::Enum(e) => Err(syn::Error::new(
-            e.enum_token.span(),
-            "`Into` cannot be derived for enums",
-        )),
+        syn::Data::Enum(data) => return expand_enum(input, data),
         syn::Data::Union(u) => Err(syn::Error::new(
             u.union_token.span(),
             "`Into` cannot be derived for unions",
@@ -106,6 +103,84 @@ pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStr
     expansions.into_iter().map(Expansion::expand).collect()
 }
 
+/// Expands an [`Into`] derive macro for an enum, generating conversions of its fieldless
+/// variants into their discriminant.
+fn expand_enum(
+    input: &syn::DeriveInput,
+    data: &syn::DataEnum,
+) -> syn::Result<TokenStream> {
+    let attr = attr::ReprConversion::parse_attrs(&input.attrs, &format_ident!("into"))?
+        .map(Spanning::into_inner)
+        .ok_or_else(|| {
+            syn::Error::new(
+                data.enum_token.span(),
+                "`Into` can be derived for enums only with `#[into(repr)]` or \
+                 `#[into(repr(<types>))]` attribute",
+            )
+        })?;
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(syn::Error::new(
+            variant.span(),
+            "`Into` can be derived only for enums with fieldless variants",
+        ));
+    }
+    let repr_ty = attr::ReprInt::parse_attrs(&input.attrs, &format_ident!("repr"))?
+        .map(Spanning::into_inner)
+        .unwrap_or_default()
+        .ty();
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let impls = match attr {
+        attr::ReprConversion::Discriminant(_) => vec![quote! {
+            #[automatically_derived]
+            impl #impl_generics derive_more::core::convert::From<#ident #ty_generics>
+                 for #repr_ty #where_clause
+            {
+                #[inline]
+                fn from(value: #ident #ty_generics) -> Self {
+                    value.to_repr()
+                }
+            }
+        }],
+        attr::ReprConversion::Types(tys) => tys
+            .0
+            .iter()
+            .map(|ty| {
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_generics derive_more::core::convert::From<#ident #ty_generics>
+                         for #ty #where_clause
+                    {
+                        #[inline]
+                        fn from(value: #ident #ty_generics) -> Self {
+                            <#ty as derive_more::core::convert::From<#repr_ty>>::from(
+                                value.to_repr(),
+                            )
+                        }
+                    }
+                }
+            })
+            .collect(),
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Returns the discriminant of this value.
+            #[inline]
+            #[must_use]
+            #vis const fn to_repr(self) -> #repr_ty {
+                self as #repr_ty
+            }
+        }
+
+        #( #impls )*
+    })
+}
+
 /// Expansion of an [`Into`] derive macro, generating [`From`] implementations for a struct.
 struct Expansion<'a> {
     /// [`syn::Ident`] of the struct.
@@ -560,6 +635,7 @@ where
                                     None
                                 }
                             }
+                            polyfill::Meta::NameValue(_) => None,
                         }
                     };
 
@@ -576,7 +652,7 @@ where
             .filter(|(top_level, owned, ref_, ref_mut)| {
                 [top_level, owned, ref_, ref_mut]
                     .into_iter()
-                    .any(|l| l.as_ref().map_or(false, |l| !l.is_empty()))
+                    .any(|l| l.as_ref().is_some_and(|l| !l.is_empty()))
             })
         else {
             return Ok(());
@@ -1,44 +1,92 @@
-use crate::utils::{AttrParams, DeriveType, State};
+use crate::utils::{
+    attr::{self, ParseMultiple},
+    Spanning,
+};
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{DeriveInput, Fields, Result};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+    token, DeriveInput, Fields, Result,
+};
 
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
-    let state = State::with_attr_params(
-        input,
-        trait_name,
-        "is_variant".into(),
-        AttrParams {
-            enum_: vec!["ignore"],
-            variant: vec!["ignore"],
-            struct_: vec!["ignore"],
-            field: vec!["ignore"],
-        },
-    )?;
-    assert!(
-        state.derive_type == DeriveType::Enum,
-        "IsVariant can only be derived for enums",
-    );
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            format!("{trait_name} can only be derived for enums"),
+        ));
+    };
+
+    let attr_name = format_ident!("is_variant");
+    let enum_attr = Attribute::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner)
+        .unwrap_or_default();
+    if let Some(arg) = enum_attr.rename.as_ref().or(enum_attr.groups.first()) {
+        return Err(syn::Error::new(
+            arg.span(),
+            "`rename` and `group` arguments are allowed only on enum variants",
+        ));
+    }
+    let variant_attrs = data
+        .variants
+        .iter()
+        .map(|variant| {
+            Ok(Attribute::parse_attrs(&variant.attrs, &attr_name)?
+                .map(Spanning::into_inner)
+                .unwrap_or_default())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // If the first variant having `#[is_variant]` or `#[is_variant(ignore)]` attribute explicitly
+    // enables it, then all the other variants are disabled by default.
+    let default_enabled = !enum_attr.ignore
+        && variant_attrs
+            .iter()
+            .find(|attr| attr.enable || attr.ignore)
+            .map_or(true, |attr| attr.ignore);
 
     let enum_name = &input.ident;
     let (imp_generics, type_generics, where_clause) = input.generics.split_for_impl();
 
     let mut funcs = vec![];
-    for variant_state in state.enabled_variant_data().variant_states {
-        let variant = variant_state.variant.unwrap();
-        let fn_name = format_ident!(
-            "is_{}",
-            variant.ident.to_string().to_case(Case::Snake),
-            span = variant.ident.span(),
-        );
+    let mut groups = Vec::<(syn::Ident, Vec<&syn::Ident>, Vec<TokenStream>)>::new();
+    for (variant, attr) in data.variants.iter().zip(&variant_attrs) {
         let variant_ident = &variant.ident;
-
         let data_pattern = match variant.fields {
             Fields::Named(_) => quote! { {..} },
             Fields::Unnamed(_) => quote! { (..) },
             Fields::Unit => quote! {},
         };
+        let pattern = quote! { #enum_name :: #variant_ident #data_pattern };
+
+        for group in &attr.groups {
+            match groups.iter_mut().find(|(name, ..)| name == group) {
+                Some((_, idents, patterns)) => {
+                    idents.push(variant_ident);
+                    patterns.push(pattern.clone());
+                }
+                None => groups.push((
+                    group.clone(),
+                    vec![variant_ident],
+                    vec![pattern.clone()],
+                )),
+            }
+        }
+
+        if attr.ignore || !(attr.enable || default_enabled) {
+            continue;
+        }
+
+        let fn_name = attr.rename.clone().unwrap_or_else(|| {
+            format_ident!(
+                "is_{}",
+                variant_ident.to_string().to_case(Case::Snake),
+                span = variant_ident.span(),
+            )
+        });
+
         let func = quote! {
             #[doc = "Returns `true` if this value is of type `"]
             #[doc = stringify!(#variant_ident)]
@@ -46,10 +94,39 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
             #[inline]
             #[must_use]
             pub const fn #fn_name(&self) -> bool {
-                derive_more::core::matches!(self, #enum_name ::#variant_ident #data_pattern)
+                derive_more::core::matches!(self, #pattern)
             }
         };
         funcs.push(func);
+
+        if attr.and || enum_attr.and && !variant.fields.is_empty() {
+            if variant.fields.is_empty() {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    "`#[is_variant(and)]` cannot be used on variants without fields",
+                ));
+            }
+            funcs.push(and_func(enum_name, variant, &fn_name));
+        }
+    }
+
+    for (fn_name, idents, patterns) in groups {
+        let doc = format!(
+            "Returns `true` if this value is of any of {} types. Returns `false` otherwise",
+            idents
+                .iter()
+                .map(|ident| format!("`{ident}`"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        funcs.push(quote! {
+            #[doc = #doc]
+            #[inline]
+            #[must_use]
+            pub const fn #fn_name(&self) -> bool {
+                derive_more::core::matches!(self, #( #patterns )|*)
+            }
+        });
     }
 
     let imp = quote! {
@@ -61,3 +138,133 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
 
     Ok(imp)
 }
+
+/// Generates an `is_<variant>_and()` method, checking the provided predicate against the fields
+/// of the variant.
+fn and_func(
+    enum_name: &syn::Ident,
+    variant: &syn::Variant,
+    fn_name: &syn::Ident,
+) -> TokenStream {
+    let variant_ident = &variant.ident;
+    let and_fn_name = format_ident!("{fn_name}_and", span = fn_name.span());
+    let bindings = (0..variant.fields.len())
+        .map(|n| format_ident!("field_{n}"))
+        .collect::<Vec<_>>();
+    let data_pattern = match &variant.fields {
+        Fields::Named(fields) => {
+            let idents = fields.named.iter().map(|f| &f.ident);
+            quote! { { #( #idents: #bindings ),* } }
+        }
+        Fields::Unnamed(_) => quote! { ( #( #bindings ),* ) },
+        Fields::Unit => quote! {},
+    };
+    let field_tys = variant.fields.iter().map(|f| &f.ty);
+    let doc = format!(
+        "Returns `true` if this value is of type `{variant_ident}` and its fields satisfy the \
+         provided predicate. Returns `false` otherwise",
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        pub fn #and_fn_name(
+            &self,
+            f: impl derive_more::core::ops::FnOnce(#( &#field_tys ),*) -> bool,
+        ) -> bool {
+            match self {
+                #enum_name :: #variant_ident #data_pattern => f(#( #bindings ),*),
+                #[allow(unreachable_patterns)]
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Representation of an `#[is_variant]` attribute, placed on an enum or its variant.
+///
+/// ```rust,ignore
+/// #[is_variant]
+/// #[is_variant(ignore)]
+/// #[is_variant(and)]
+/// #[is_variant(rename = "<name>", group = "<group>", group = "<group>", and)]
+/// ```
+#[derive(Default)]
+struct Attribute {
+    /// Indicator whether the method should be explicitly generated (`#[is_variant]`).
+    enable: bool,
+
+    /// Indicator whether the method shouldn't be generated (`#[is_variant(ignore)]`).
+    ignore: bool,
+
+    /// Custom name of the generated method.
+    rename: Option<syn::Ident>,
+
+    /// Names of the `is_<group>()` methods covering all the variants of their groups.
+    groups: Vec<syn::Ident>,
+
+    /// Indicator whether the `is_<variant>_and()` method should be generated too.
+    and: bool,
+}
+
+impl Parse for Attribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "ignore" | "skip" => out.ignore = true,
+                "and" => out.and = true,
+                "rename" => {
+                    input.parse::<token::Eq>()?;
+                    let lit = input.parse::<syn::LitStr>()?;
+                    let name = lit.parse::<syn::Ident>().map_err(|_| {
+                        syn::Error::new(lit.span(), "expected a method name")
+                    })?;
+                    if out.rename.replace(name).is_some() {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "only single `rename = \"...\"` argument is allowed here",
+                        ));
+                    }
+                }
+                "group" => {
+                    input.parse::<token::Eq>()?;
+                    let lit = input.parse::<syn::LitStr>()?;
+                    let name =
+                        syn::LitStr::new(&format!("is_{}", lit.value()), lit.span())
+                            .parse::<syn::Ident>()
+                            .map_err(|_| {
+                                syn::Error::new(lit.span(), "expected a group name")
+                            })?;
+                    out.groups.push(name);
+                }
+                _ => return Err(syn::Error::new(
+                    ident.span(),
+                    "unknown argument, only `ignore`, `rename`, `group` and `and` are \
+                         allowed here",
+                )),
+            }
+            if !input.is_empty() {
+                input.parse::<token::Comma>()?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl ParseMultiple for Attribute {
+    fn parse_attr_with<P: attr::Parser>(
+        attr: &syn::Attribute,
+        parser: &P,
+    ) -> Result<Self> {
+        if matches!(attr.meta, syn::Meta::Path(_)) {
+            return Ok(Self {
+                enable: true,
+                ..Self::default()
+            });
+        }
+        attr.parse_args_with(|ps: ParseStream<'_>| parser.parse(ps))
+    }
+}
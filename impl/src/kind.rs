@@ -0,0 +1,162 @@
+//! Implementation of a [`Kind`] derive macro.
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    Spanning,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token, DeriveInput, Fields, Result,
+};
+
+/// Expands a [`Kind`] derive macro.
+pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            format!("`{trait_name}` can only be derived for enums"),
+        ));
+    };
+
+    let attr_name = format_ident!("kind");
+    let attr = Attribute::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner)
+        .unwrap_or_default();
+    for variant in &data.variants {
+        if let Some(attr) = variant.attrs.iter().find(|a| a.path().is_ident(&attr_name))
+        {
+            return Err(syn::Error::new(
+                attr.span(),
+                "`#[kind(...)]` attribute is allowed only on the enum itself",
+            ));
+        }
+    }
+
+    let enum_name = &input.ident;
+    let vis = &input.vis;
+    let kind_name = attr
+        .name
+        .unwrap_or_else(|| format_ident!("{enum_name}Kind", span = enum_name.span()));
+    let derives = attr.derives;
+    let (imp_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = data.variants.iter().map(|variant| {
+        // `#[cfg]` attributes should be forwarded to keep the kinds in sync with the variants.
+        let attrs = variant
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("doc") || a.path().is_ident("cfg"));
+        let ident = &variant.ident;
+        quote! { #( #attrs )* #ident }
+    });
+    let arms = data.variants.iter().map(|variant| {
+        let cfgs = variant.attrs.iter().filter(|a| a.path().is_ident("cfg"));
+        let ident = &variant.ident;
+        let data_pattern = match variant.fields {
+            Fields::Named(_) => quote! { {..} },
+            Fields::Unnamed(_) => quote! { (..) },
+            Fields::Unit => quote! {},
+        };
+        quote! { #( #cfgs )* #enum_name :: #ident #data_pattern => #kind_name :: #ident, }
+    });
+
+    let kind_doc = format!("Fieldless kind of a [`{enum_name}`] value.");
+    let fn_doc = format!("Returns the [`{kind_name}`] of this value.");
+
+    Ok(quote! {
+        #[doc = #kind_doc]
+        #[derive(
+            derive_more::core::clone::Clone,
+            derive_more::core::marker::Copy,
+            derive_more::core::fmt::Debug,
+            derive_more::core::cmp::Eq,
+            derive_more::core::hash::Hash,
+            derive_more::core::cmp::PartialEq,
+            #( #derives ),*
+        )]
+        #vis enum #kind_name {
+            #( #variants ),*
+        }
+
+        #[automatically_derived]
+        impl #imp_generics #enum_name #type_generics #where_clause {
+            #[doc = #fn_doc]
+            #[inline]
+            #[must_use]
+            #vis const fn kind(&self) -> #kind_name {
+                match *self {
+                    #( #arms )*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #imp_generics derive_more::core::convert::From<&#enum_name #type_generics>
+         for #kind_name #where_clause
+        {
+            #[inline]
+            fn from(value: &#enum_name #type_generics) -> Self {
+                value.kind()
+            }
+        }
+    })
+}
+
+/// Representation of a `#[kind(...)]` attribute, placed on an enum.
+///
+/// ```rust,ignore
+/// #[kind(name = <ident>, derive(<path>, ...))]
+/// ```
+#[derive(Default)]
+struct Attribute {
+    /// Name of the generated kind enum.
+    name: Option<syn::Ident>,
+
+    /// Additional derives to be placed on the generated kind enum.
+    derives: Vec<syn::Path>,
+}
+
+impl Parse for Attribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    if out.name.replace(input.parse()?).is_some() {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "only single `name = ...` argument is allowed here",
+                        ));
+                    }
+                }
+                "derive" => {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    out.derives.extend(
+                        Punctuated::<syn::Path, token::Comma>::parse_terminated(
+                            &content,
+                        )?,
+                    );
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "unknown argument, only `name` and `derive` are allowed here",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<token::Comma>()?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl attr::ParseMultiple for Attribute {}
@@ -17,12 +17,16 @@ mod add_assign_like;
     feature = "add_assign",
     feature = "mul",
     feature = "mul_assign",
+    feature = "not",
+    feature = "sum",
 ))]
 mod add_helpers;
 #[cfg(any(feature = "add", feature = "mul"))]
 mod add_like;
 #[cfg(feature = "as_ref")]
 mod r#as;
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "constructor")]
 mod constructor;
 #[cfg(feature = "deref")]
@@ -31,12 +35,14 @@ mod deref;
 mod deref_mut;
 #[cfg(feature = "error")]
 mod error;
-#[cfg(any(feature = "debug", feature = "display"))]
+#[cfg(any(feature = "debug", feature = "display", feature = "from_str"))]
 mod fmt;
 #[cfg(feature = "from")]
 mod from;
 #[cfg(feature = "from_str")]
 mod from_str;
+#[cfg(feature = "getters")]
+mod getters;
 #[cfg(feature = "index")]
 mod index;
 #[cfg(feature = "index_mut")]
@@ -47,6 +53,8 @@ mod into;
 mod into_iterator;
 #[cfg(feature = "is_variant")]
 mod is_variant;
+#[cfg(feature = "kind")]
+mod kind;
 #[cfg(feature = "mul_assign")]
 mod mul_assign_like;
 #[cfg(any(feature = "mul", feature = "mul_assign"))]
@@ -55,8 +63,10 @@ mod mul_helpers;
 mod mul_like;
 #[cfg(feature = "not")]
 mod not_like;
-#[cfg(any(feature = "debug", feature = "display"))]
+#[cfg(any(feature = "debug", feature = "display", feature = "from_str"))]
 pub(crate) mod parsing;
+#[cfg(feature = "setters")]
+mod setters;
 #[cfg(feature = "sum")]
 mod sum_like;
 #[cfg(feature = "try_from")]
@@ -67,6 +77,8 @@ mod try_into;
 mod try_unwrap;
 #[cfg(feature = "unwrap")]
 mod unwrap;
+#[cfg(feature = "variants")]
+mod variants;
 
 // This trait describes the possible return types of
 // the derives. A derive can generally be infallible and
@@ -107,37 +119,60 @@ macro_rules! create_derive(
     }
 );
 
-create_derive!("add", add_like, Add, add_derive);
-create_derive!("add", add_like, Sub, sub_derive);
-create_derive!("add", add_like, BitAnd, bit_and_derive);
-create_derive!("add", add_like, BitOr, bit_or_derive);
-create_derive!("add", add_like, BitXor, bit_xor_derive);
+create_derive!("add", add_like, Add, add_derive, add);
+create_derive!("add", add_like, Sub, sub_derive, sub);
+create_derive!("add", add_like, BitAnd, bit_and_derive, bitand);
+create_derive!("add", add_like, BitOr, bit_or_derive, bitor);
+create_derive!("add", add_like, BitXor, bit_xor_derive, bitxor);
 
-create_derive!("add_assign", add_assign_like, AddAssign, add_assign_derive,);
-create_derive!("add_assign", add_assign_like, SubAssign, sub_assign_derive,);
+create_derive!(
+    "add_assign",
+    add_assign_like,
+    AddAssign,
+    add_assign_derive,
+    add_assign,
+);
+create_derive!(
+    "add_assign",
+    add_assign_like,
+    SubAssign,
+    sub_assign_derive,
+    sub_assign,
+);
 create_derive!(
     "add_assign",
     add_assign_like,
     BitAndAssign,
     bit_and_assign_derive,
+    bitand_assign,
 );
 create_derive!(
     "add_assign",
     add_assign_like,
     BitOrAssign,
     bit_or_assign_derive,
+    bitor_assign,
 );
 create_derive!(
     "add_assign",
     add_assign_like,
     BitXorAssign,
     bit_xor_assign_derive,
+    bitxor_assign,
 );
 
 create_derive!("as_ref", r#as::r#mut, AsMut, as_mut_derive, as_mut);
 create_derive!("as_ref", r#as::r#ref, AsRef, as_ref_derive, as_ref);
 
-create_derive!("constructor", constructor, Constructor, constructor_derive);
+create_derive!("builder", builder, Builder, builder_derive, new);
+
+create_derive!(
+    "constructor",
+    constructor,
+    Constructor,
+    constructor_derive,
+    new
+);
 
 create_derive!("debug", fmt::debug, Debug, debug_derive, debug);
 
@@ -188,7 +223,9 @@ create_derive!("error", error, Error, error_derive, error);
 
 create_derive!("from", from, From, from_derive, from);
 
-create_derive!("from_str", from_str, FromStr, from_str_derive);
+create_derive!("from_str", from_str, FromStr, from_str_derive, from_str);
+
+create_derive!("getters", getters, Getters, getters_derive, getter);
 
 create_derive!("index", index, Index, index_derive, index);
 
@@ -218,6 +255,8 @@ create_derive!(
     is_variant,
 );
 
+create_derive!("kind", kind, Kind, kind_derive, kind);
+
 create_derive!("mul", mul_like, Mul, mul_derive, mul);
 create_derive!("mul", mul_like, Div, div_derive, div);
 create_derive!("mul", mul_like, Rem, rem_derive, rem);
@@ -260,11 +299,13 @@ create_derive!(
     shl_assign,
 );
 
-create_derive!("not", not_like, Not, not_derive);
-create_derive!("not", not_like, Neg, neg_derive);
+create_derive!("not", not_like, Not, not_derive, not);
+create_derive!("not", not_like, Neg, neg_derive, neg);
 
-create_derive!("sum", sum_like, Sum, sum_derive);
-create_derive!("sum", sum_like, Product, product_derive);
+create_derive!("setters", setters, Setters, setters_derive, setter);
+
+create_derive!("sum", sum_like, Sum, sum_derive, sum);
+create_derive!("sum", sum_like, Product, product_derive, product);
 
 create_derive!("try_from", try_from, TryFrom, try_from_derive, try_from);
 
@@ -279,3 +320,12 @@ create_derive!(
 );
 
 create_derive!("unwrap", unwrap, Unwrap, unwrap_derive, unwrap);
+
+create_derive!(
+    "variants",
+    variants,
+    Variants,
+    variants_derive,
+    from_str,
+    display,
+);
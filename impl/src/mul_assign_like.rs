@@ -1,10 +1,11 @@
 use crate::add_assign_like;
+use crate::add_helpers::{skipped_where_clauses, Attributes, Skip, Skips};
 use crate::mul_helpers::generics_and_exprs;
 use crate::utils::{AttrParams, HashSet, MultiFieldData, RefType, State};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use std::iter;
-use syn::{DeriveInput, Result};
+use syn::{DeriveInput, Index, Result};
 
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
     let method_name = trait_name
@@ -13,14 +14,26 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         .to_string()
         + "_assign";
 
+    let skips = Skips::parse(input, &format_ident!("{method_name}"))?;
     let mut state = State::with_attr_params(
         input,
         trait_name,
         method_name,
-        AttrParams::struct_(vec!["forward"]),
+        AttrParams {
+            struct_: vec!["forward"],
+            field: vec!["skip", "keep", "default"],
+            ..AttrParams::default()
+        },
     )?;
     if state.default_info.forward {
-        return Ok(add_assign_like::expand(input, trait_name));
+        return Ok(add_assign_like::expand_with_attrs(
+            input,
+            trait_name,
+            &Attributes {
+                skips,
+                ..Attributes::default()
+            },
+        ));
     }
     let scalar_ident = format_ident!("__RhsT");
     state.add_trait_path_type_param(quote! { #scalar_ident });
@@ -39,16 +52,31 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
     let tys = tys.iter();
     let trait_path_iter = iter::repeat(trait_path_with_params);
 
+    let skipped = skipped_where_clauses(input, &skips, false);
     let type_where_clauses = quote! {
-        where #(#tys: #trait_path_iter),*
+        where #(#tys: #trait_path_iter,)*
+              #(#skipped),*
     };
 
-    let (generics, exprs) = generics_and_exprs(
+    let (generics, mut exprs) = generics_and_exprs(
         multi_field_data.clone(),
         &scalar_ident,
         type_where_clauses,
         RefType::Mut,
     );
+    // Fields skipped with `default` are reset, while the kept ones are left untouched.
+    for (i, (field, skip)) in state.fields.iter().zip(skips.of(0)).enumerate() {
+        if *skip == Some(Skip::Default) {
+            let member = field.ident.as_ref().map_or_else(
+                || Index::from(i).to_token_stream(),
+                ToTokens::to_token_stream,
+            );
+            // generates `self.x = Default::default()`
+            exprs.push(quote! {
+                self.#member = derive_more::core::default::Default::default()
+            });
+        }
+    }
     let (impl_generics, _, where_clause) = generics.split_for_impl();
 
     Ok(quote! {
@@ -1,20 +1,36 @@
+use crate::add_helpers::{
+    skipped_expr, skipped_where_clauses, Attributes, Skip, Skips,
+};
 use crate::add_like;
 use crate::mul_helpers::generics_and_exprs;
 use crate::utils::{AttrParams, HashSet, MultiFieldData, RefType, State};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use std::iter;
-use syn::{DeriveInput, Result};
+use syn::{parse_quote, DeriveInput, Index, Result};
 
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    let skips = Skips::parse(input, &format_ident!("{}", trait_name.to_lowercase()))?;
     let mut state = State::with_attr_params(
         input,
         trait_name,
         trait_name.to_lowercase(),
-        AttrParams::struct_(vec!["forward"]),
+        AttrParams {
+            struct_: vec!["forward", "ref"],
+            field: vec!["skip", "keep", "default"],
+            ..AttrParams::default()
+        },
     )?;
     if state.default_info.forward {
-        return Ok(add_like::expand(input, trait_name));
+        return Ok(add_like::expand_with_attrs(
+            input,
+            trait_name,
+            &Attributes {
+                by_ref: state.default_info.ref_,
+                skips,
+                ..Attributes::default()
+            },
+        ));
     }
 
     let scalar_ident = format_ident!("__RhsT");
@@ -35,8 +51,10 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
     let scalar_iter = iter::repeat(&scalar_ident);
     let trait_path_iter = iter::repeat(trait_path);
 
+    let skipped = skipped_where_clauses(input, &skips, false);
     let type_where_clauses = quote! {
-        where #(#tys: #trait_path_iter<#scalar_iter, Output=#tys>),*
+        where #(#tys: #trait_path_iter<#scalar_iter, Output=#tys>,)*
+              #(#skipped),*
     };
 
     let (generics, initializers) = generics_and_exprs(
@@ -45,13 +63,68 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         type_where_clauses,
         RefType::No,
     );
-    let body = multi_field_data.initializer(&initializers);
+    let body = initializer(&multi_field_data, skips.of(0), initializers, false);
     let (impl_generics, _, where_clause) = generics.split_for_impl();
-    Ok(quote! {
+    let by_value = quote! {
         #[automatically_derived]
         impl #impl_generics  #trait_path_with_params for #input_type #ty_generics #where_clause {
             type Output = #input_type #ty_generics;
 
+            #[inline]
+            #[track_caller]
+            fn #method_ident(self, rhs: #scalar_ident) -> #input_type #ty_generics {
+                #body
+            }
+        }
+    };
+    if !state.default_info.ref_ {
+        return Ok(by_value);
+    }
+
+    // With `#[mul(ref)]` the operation is additionally implemented for a reference, applying it
+    // to the references of the fields.
+    let skipped = skipped_where_clauses(input, &skips, true);
+    let tys = field_types.iter().collect::<HashSet<_>>();
+    let tys = tys.iter();
+    let scalar_iter = iter::repeat(&scalar_ident);
+    let trait_path_iter = iter::repeat(trait_path);
+    let type_where_clauses = quote! {
+        where #(
+            for<'__deriveMoreField> &'__deriveMoreField #tys: #trait_path_iter<
+                #scalar_iter, Output = #tys,
+            >,
+        )*
+        #(#skipped),*
+    };
+    let (generics, _) = generics_and_exprs(
+        multi_field_data.clone(),
+        &scalar_ident,
+        type_where_clauses,
+        RefType::Ref,
+    );
+    let (_, _, where_clause) = generics.split_for_impl();
+    let lifetime = RefType::Ref.lifetime();
+    let reference = RefType::Ref.reference_with_lifetime();
+    let mut ref_generics = generics.clone();
+    ref_generics.params.insert(0, parse_quote! { #lifetime });
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+    // generates `x: derive_more::Mul::mul(&self.x, rhs)`
+    let initializers = multi_field_data
+        .members
+        .iter()
+        .map(|member| quote! { #trait_path::#method_ident(&#member, rhs) })
+        .collect::<Vec<_>>();
+    let body = initializer(&multi_field_data, skips.of(0), initializers, true);
+
+    Ok(quote! {
+        #by_value
+
+        #[automatically_derived]
+        impl #ref_impl_generics #trait_path_with_params for #reference #input_type #ty_generics
+         #where_clause
+        {
+            type Output = #input_type #ty_generics;
+
             #[inline]
             #[track_caller]
             fn #method_ident(self, rhs: #scalar_ident) -> #input_type #ty_generics {
@@ -60,3 +133,41 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         }
     })
 }
+
+/// Generates an initializer of the struct from the provided expressions of its operated fields,
+/// filling the skipped ones according to the provided [`Skip`]s.
+///
+/// Kept fields are cloned, if `by_ref` is `true`.
+fn initializer(
+    multi_field_data: &MultiFieldData<'_, '_>,
+    skips: &[Option<Skip>],
+    exprs: Vec<TokenStream>,
+    by_ref: bool,
+) -> TokenStream {
+    if skips.iter().all(Option::is_none) {
+        return multi_field_data.initializer(&exprs);
+    }
+
+    let variant_type = &multi_field_data.variant_type;
+    let mut exprs = exprs.into_iter();
+    let fields = multi_field_data
+        .state
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let member = field.ident.as_ref().map_or_else(
+                || Index::from(i).to_token_stream(),
+                ToTokens::to_token_stream,
+            );
+            let expr = match skips.get(i).copied().flatten() {
+                Some(Skip::KeepLhs) if by_ref => {
+                    quote! { derive_more::core::clone::Clone::clone(&self.#member) }
+                }
+                Some(skip) => skipped_expr(skip, quote! { self.#member }),
+                None => exprs.next().to_token_stream(),
+            };
+            quote! { #member: #expr }
+        });
+    quote! { #variant_type { #( #fields ),* } }
+}
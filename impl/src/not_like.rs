@@ -1,40 +1,66 @@
+use crate::add_helpers::{skipped_expr, skipped_where_clauses, Skip, Skips};
 use crate::utils::{
-    add_extra_type_param_bound_op_output, named_to_vec, unnamed_to_vec,
+    add_extra_type_param_bound_op_output, add_extra_where_clauses, named_to_vec,
+    unnamed_to_vec,
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use std::iter;
-use syn::{Data, DataEnum, DeriveInput, Field, Fields, Ident, Index};
+use syn::{Data, DataEnum, DeriveInput, Field, Fields, Ident, Index, Result};
 
-pub fn expand(input: &DeriveInput, trait_name: &str) -> TokenStream {
+pub fn expand(input: &DeriveInput, trait_name: &str) -> Result<TokenStream> {
     let trait_ident = format_ident!("{trait_name}");
     let method_name = trait_name.to_lowercase();
     let method_ident = format_ident!("{method_name}");
     let input_type = &input.ident;
-
-    let generics = add_extra_type_param_bound_op_output(&input.generics, &trait_ident);
+    let skips = Skips::parse(input, &method_ident)?;
+
+    let generics = if skips.any() {
+        // Skipped fields may use type parameters not supporting the operation at all, so the
+        // types of the operated fields are bounded instead.
+        let tys = skips.operand_types(input);
+        let skipped = skipped_where_clauses(input, &skips, false);
+        add_extra_where_clauses(
+            &input.generics,
+            quote! {
+                where #( #tys: derive_more::core::ops::#trait_ident<Output = #tys>, )*
+                      #( #skipped ),*
+            },
+        )
+    } else {
+        add_extra_type_param_bound_op_output(&input.generics, &trait_ident)
+    };
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let (output_type, block) = match input.data {
         Data::Struct(ref data_struct) => match data_struct.fields {
             Fields::Unnamed(ref fields) => (
                 quote! { #input_type #ty_generics },
-                tuple_content(input_type, &unnamed_to_vec(fields), &method_ident),
+                tuple_content(
+                    input_type,
+                    &unnamed_to_vec(fields),
+                    &method_ident,
+                    skips.of(0),
+                ),
             ),
             Fields::Named(ref fields) => (
                 quote! { #input_type #ty_generics },
-                struct_content(input_type, &named_to_vec(fields), &method_ident),
+                struct_content(
+                    input_type,
+                    &named_to_vec(fields),
+                    &method_ident,
+                    skips.of(0),
+                ),
             ),
             _ => panic!("Unit structs cannot use derive({trait_name})"),
         },
         Data::Enum(ref data_enum) => {
-            enum_output_type_and_content(input, data_enum, &method_ident)
+            enum_output_type_and_content(input, data_enum, &method_ident, &skips)
         }
 
         _ => panic!("Only structs and enums can use derive({trait_name})"),
     };
 
-    quote! {
+    Ok(quote! {
         #[automatically_derived]
         impl #impl_generics derive_more::#trait_ident for #input_type #ty_generics #where_clause {
             type Output = #output_type;
@@ -44,20 +70,26 @@ pub fn expand(input: &DeriveInput, trait_name: &str) -> TokenStream {
                 #block
             }
         }
-    }
+    })
 }
 
 fn tuple_content<T: ToTokens>(
     input_type: &T,
     fields: &[&Field],
     method_ident: &Ident,
+    skips: &[Option<Skip>],
 ) -> TokenStream {
     let mut exprs = vec![];
 
     for i in 0..fields.len() {
+        let skip = skips.get(i).copied().flatten();
         let i = Index::from(i);
-        // generates `self.0.add()`
-        let expr = quote! { self.#i.#method_ident() };
+        let expr = match skip {
+            // generates `self.0`
+            Some(skip) => skipped_expr(skip, quote! { self.#i }),
+            // generates `self.0.add()`
+            None => quote! { self.#i.#method_ident() },
+        };
         exprs.push(expr);
     }
 
@@ -68,14 +100,22 @@ fn struct_content(
     input_type: &Ident,
     fields: &[&Field],
     method_ident: &Ident,
+    skips: &[Option<Skip>],
 ) -> TokenStream {
     let mut exprs = vec![];
 
-    for field in fields {
+    for (i, field) in fields.iter().enumerate() {
         // It's safe to unwrap because struct fields always have an identifier
         let field_id = field.ident.as_ref();
-        // generates `x: self.x.not()`
-        let expr = quote! { #field_id: self.#field_id.#method_ident() };
+        let expr = match skips.get(i).copied().flatten() {
+            // generates `x: self.x`
+            Some(skip) => {
+                let value = skipped_expr(skip, quote! { self.#field_id });
+                quote! { #field_id: #value }
+            }
+            // generates `x: self.x.not()`
+            None => quote! { #field_id: self.#field_id.#method_ident() },
+        };
         exprs.push(expr)
     }
 
@@ -86,27 +126,26 @@ fn enum_output_type_and_content(
     input: &DeriveInput,
     data_enum: &DataEnum,
     method_ident: &Ident,
+    skips: &Skips,
 ) -> (TokenStream, TokenStream) {
     let input_type = &input.ident;
     let (_, ty_generics, _) = input.generics.split_for_impl();
     let mut matches = vec![];
-    let mut method_iter = iter::repeat(method_ident);
     // If the enum contains unit types that means it can error.
     let has_unit_type = data_enum.variants.iter().any(|v| v.fields == Fields::Unit);
 
-    for variant in &data_enum.variants {
+    for (i, variant) in data_enum.variants.iter().enumerate() {
         let subtype = &variant.ident;
         let subtype = quote! { #input_type::#subtype };
+        let skips = skips.of(i);
 
         match variant.fields {
             Fields::Unnamed(ref fields) => {
                 // The pattern that is outputted should look like this:
                 // (Subtype(vars)) => Ok(TypePath(exprs))
                 let size = unnamed_to_vec(fields).len();
-                let vars: &Vec<_> =
-                    &(0..size).map(|i| format_ident!("__{i}")).collect();
-                let method_iter = method_iter.by_ref();
-                let mut body = quote! { #subtype(#(#vars.#method_iter()),*) };
+                let (vars, exprs) = variant_field_parts(size, skips, method_ident);
+                let mut body = quote! { #subtype(#(#exprs),*) };
                 if has_unit_type {
                     body = quote! { derive_more::core::result::Result::Ok(#body) }
                 }
@@ -128,11 +167,9 @@ fn enum_output_type_and_content(
                     .iter()
                     .map(|f| f.ident.as_ref().unwrap())
                     .collect();
-                let vars: &Vec<_> =
-                    &(0..size).map(|i| format_ident!("__{i}")).collect();
-                let method_iter = method_iter.by_ref();
+                let (vars, exprs) = variant_field_parts(size, skips, method_ident);
                 let mut body = quote! {
-                    #subtype{#(#field_names: #vars.#method_iter()),*}
+                    #subtype{#(#field_names: #exprs),*}
                 };
                 if has_unit_type {
                     body = quote! { derive_more::core::result::Result::Ok(#body) }
@@ -171,3 +208,22 @@ fn enum_output_type_and_content(
 
     (output_type, body)
 }
+
+/// Generates the patterns binding the fields of an enum variant, along with the expressions
+/// computing them, considering the provided [`Skip`]s.
+fn variant_field_parts(
+    size: usize,
+    skips: &[Option<Skip>],
+    method_ident: &Ident,
+) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    (0..size)
+        .map(|i| {
+            let var = format_ident!("__{i}");
+            match skips.get(i).copied().flatten() {
+                None => (quote! { #var }, quote! { #var.#method_ident() }),
+                Some(Skip::KeepLhs) => (quote! { #var }, quote! { #var }),
+                Some(skip @ Skip::Default) => (quote! { _ }, skipped_expr(skip, &var)),
+            }
+        })
+        .unzip()
+}
@@ -0,0 +1,81 @@
+//! Implementation of a [`Setters`] derive macro.
+
+use crate::utils::{AttrParams, State};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens as _};
+use syn::{spanned::Spanned as _, Data, DeriveInput, Fields, Result};
+
+/// Expands a [`Setters`] derive macro.
+pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            format!("`{trait_name}` can only be derived for structs"),
+        ));
+    };
+    if matches!(data.fields, Fields::Unnamed(_)) {
+        return Err(syn::Error::new(
+            data.fields.span(),
+            format!("`{trait_name}` can only be derived for structs with named fields"),
+        ));
+    }
+
+    let state = State::with_attr_params(
+        input,
+        trait_name,
+        "setter".into(),
+        AttrParams {
+            enum_: vec![],
+            variant: vec![],
+            struct_: vec![],
+            field: vec!["skip", "into", "rename"],
+        },
+    )?;
+    let data = state.enabled_fields_data();
+
+    let vis = &input.vis;
+    let setters = data.fields.iter().zip(&data.infos).map(|(field, info)| {
+        let field_ident = field.ident.as_ref().unwrap();
+        let field_ty = &field.ty;
+        let name = info.info.rename.as_ref().unwrap_or(field_ident);
+        let set_name = format_ident!("set_{name}", span = name.span());
+        let with_name = format_ident!("with_{name}", span = name.span());
+        let docs = field.attrs.iter().filter(|a| a.path().is_ident("doc"));
+        let with_docs = docs.clone();
+
+        let (param_ty, value) = if info.info.into.unwrap_or_default() {
+            (
+                quote! { impl derive_more::core::convert::Into<#field_ty> },
+                quote! { derive_more::core::convert::Into::<#field_ty>::into(value) },
+            )
+        } else {
+            (field_ty.to_token_stream(), quote! { value })
+        };
+
+        quote! {
+            #( #docs )*
+            #[inline]
+            #vis fn #set_name(&mut self, value: #param_ty) {
+                self.#field_ident = #value;
+            }
+
+            #( #with_docs )*
+            #[inline]
+            #[must_use]
+            #vis fn #with_name(mut self, value: #param_ty) -> Self {
+                self.#field_ident = #value;
+                self
+            }
+        }
+    });
+
+    let struct_name = &input.ident;
+    let (imp_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #imp_generics #struct_name #type_generics #where_clause {
+            #( #setters )*
+        }
+    })
+}
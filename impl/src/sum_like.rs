@@ -1,12 +1,23 @@
+use crate::add_helpers::{Skip, Skips};
 use crate::utils::{
-    add_extra_ty_param_bound, add_extra_where_clauses, MultiFieldData, State,
+    add_extra_ty_param_bound, add_extra_where_clauses, AttrParams, MultiFieldData,
+    State,
 };
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
-use syn::{DeriveInput, Result};
+use quote::{format_ident, quote, ToTokens};
+use syn::{DeriveInput, Index, Result};
 
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
-    let state = State::new(input, trait_name, trait_name.to_lowercase())?;
+    let skips = Skips::parse(input, &format_ident!("{}", trait_name.to_lowercase()))?;
+    let state = State::with_attr_params(
+        input,
+        trait_name,
+        trait_name.to_lowercase(),
+        AttrParams {
+            field: vec!["skip", "keep", "default"],
+            ..AttrParams::default()
+        },
+    )?;
     let multi_field_data = state.enabled_fields_data();
     let MultiFieldData {
         input_type,
@@ -23,6 +34,22 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
     let has_type_params = input.generics.type_params().next().is_none();
     let generics = if has_type_params {
         input.generics.clone()
+    } else if skips.any() {
+        // Skipped fields may use type parameters not supporting the operation at all, so the
+        // types of the summed fields are bounded instead.
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let tys = skips.operand_types(input);
+        let skipped = [Skip::KeepLhs, Skip::Default]
+            .into_iter()
+            .flat_map(|skip| skips.types(input, skip));
+        add_extra_where_clauses(
+            &input.generics,
+            quote! {
+                where #( #tys: #trait_path, )*
+                      #( #skipped: derive_more::core::default::Default, )*
+                      #input_type #ty_generics: #op_path<Output=#input_type #ty_generics>
+            },
+        )
     } else {
         let (_, ty_generics, _) = input.generics.split_for_impl();
         let generics = add_extra_ty_param_bound(&input.generics, trait_path);
@@ -39,14 +66,44 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
             quote! { #trait_path::#method_ident(derive_more::core::iter::empty::<#field_type>()) }
         })
         .collect();
-    let identity = multi_field_data.initializer(&initializers);
+    let body = if skips.any() {
+        // Skipped fields have no identity, so the `Default` value is used instead.
+        let mut initializers = initializers.into_iter();
+        let fields = state.fields.iter().zip(skips.of(0)).enumerate().map(
+            |(i, (field, skip))| {
+                let member = field.ident.as_ref().map_or_else(
+                    || Index::from(i).to_token_stream(),
+                    ToTokens::to_token_stream,
+                );
+                let expr = match skip {
+                    Some(_) => {
+                        quote! { derive_more::core::default::Default::default() }
+                    }
+                    None => initializers.next().to_token_stream(),
+                };
+                quote! { #member: #expr }
+            },
+        );
+        let identity = quote! { #input_type { #( #fields ),* } };
+        if skips.of(0).contains(&Some(Skip::KeepLhs)) {
+            // Kept fields should have the value of the first item, not the identity's one.
+            quote! {
+                iter.reduce(#op_path::#op_method_ident).unwrap_or_else(|| #identity)
+            }
+        } else {
+            quote! { iter.fold(#identity, #op_path::#op_method_ident) }
+        }
+    } else {
+        let identity = multi_field_data.initializer(&initializers);
+        quote! { iter.fold(#identity, #op_path::#op_method_ident) }
+    };
 
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics #trait_path for #input_type #ty_generics #where_clause {
             #[inline]
             fn #method_ident<I: derive_more::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
-                iter.fold(#identity, #op_path::#op_method_ident)
+                #body
             }
         }
     })
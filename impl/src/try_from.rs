@@ -2,7 +2,11 @@
 
 use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use syn::spanned::Spanned as _;
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+    token,
+};
 
 use crate::utils::{
     attr::{self, ParseMultiple as _},
@@ -12,26 +16,38 @@ use crate::utils::{
 /// Expands a [`TryFrom`] derive macro.
 pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
     match &input.data {
-        syn::Data::Struct(data) => Err(syn::Error::new(
-            data.struct_token.span(),
-            "`TryFrom` cannot be derived for structs",
-        )),
+        syn::Data::Struct(data) => {
+            let attr =
+                StructAttribute::parse_attrs(&input.attrs, &format_ident!("try_from"))?
+                    .map(Spanning::into_inner)
+                    .ok_or_else(|| {
+                        syn::Error::new(
+                            data.struct_token.span(),
+                            "`TryFrom` can be derived for structs only with \
+                         `#[try_from(<types>, validate = <fn>)]` attribute",
+                        )
+                    })?;
+            let mut fields = data.fields.iter();
+            let (Some(field), None) = (fields.next(), fields.next()) else {
+                return Err(syn::Error::new(
+                    data.struct_token.span(),
+                    "`TryFrom` can be derived only for structs with a single field",
+                ));
+            };
+            Ok(StructExpansion {
+                attr,
+                ident: &input.ident,
+                generics: &input.generics,
+                field,
+            }
+            .into_token_stream())
+        }
         syn::Data::Enum(data) => Ok(Expansion {
             repr: attr::ReprInt::parse_attrs(&input.attrs, &format_ident!("repr"))?
                 .map(Spanning::into_inner)
                 .unwrap_or_default(),
             attr: ItemAttribute::parse_attrs(&input.attrs, &format_ident!("try_from"))?
-                .map(|attr| {
-                    if matches!(attr.item, ItemAttribute::Types(_)) {
-                        Err(syn::Error::new(
-                            attr.span,
-                            "`#[try_from(repr(...))]` attribute is not supported yet",
-                        ))
-                    } else {
-                        Ok(attr.item)
-                    }
-                })
-                .transpose()?,
+                .map(Spanning::into_inner),
             ident: input.ident.clone(),
             generics: input.generics.clone(),
             variants: data.variants.clone().into_iter().collect(),
@@ -44,7 +60,7 @@ pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStr
     }
 }
 
-/// Representation of a [`TryFrom`] derive macro struct item attribute.
+/// Representation of a [`TryFrom`] derive macro enum item attribute.
 ///
 /// ```rust,ignore
 /// #[try_from(repr)]
@@ -52,6 +68,149 @@ pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStr
 /// ```
 type ItemAttribute = attr::ReprConversion;
 
+/// Representation of a [`TryFrom`] derive macro struct item attribute.
+///
+/// ```rust,ignore
+/// #[try_from(<types>, validate = <fn-path>)]
+/// #[try_from(<types>, validate = <fn-path>, error(<ty>))]
+/// #[try_from(<types>, validate = <fn-path>, error(<ty>, <map-fn>))]
+/// ```
+struct StructAttribute {
+    /// Types to convert from.
+    types: Vec<syn::Type>,
+
+    /// Function validating the converted value of the field.
+    validate: attr::Validate,
+
+    /// Error type to return, along with the function mapping the conversion error into it.
+    ///
+    /// The conversion error of the field type is returned if [`None`].
+    error: Option<attr::Error>,
+}
+
+impl Parse for StructAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let span = input.span();
+        let mut types = vec![];
+        let mut validate = None;
+        let mut error = None;
+        while !input.is_empty() {
+            if input.peek(syn::Ident) && input.peek2(token::Eq) {
+                let arg = input.fork().parse::<syn::Ident>()?;
+                if validate.replace(input.parse::<attr::Validate>()?).is_some() {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        "only single `validate` argument is allowed here",
+                    ));
+                }
+            } else if input.peek(syn::Ident) && input.peek2(token::Paren) {
+                let arg = input.fork().parse::<syn::Ident>()?;
+                if error.replace(input.parse::<attr::Error>()?).is_some() {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        "only single `error` argument is allowed here",
+                    ));
+                }
+            } else {
+                types.push(input.parse()?);
+            }
+            if !input.is_empty() {
+                input.parse::<token::Comma>()?;
+            }
+        }
+
+        if types.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "at least one type to convert from is expected",
+            ));
+        }
+        let Some(validate) = validate else {
+            return Err(syn::Error::new(
+                span,
+                "`validate = <fn>` argument is expected",
+            ));
+        };
+        Ok(Self {
+            types,
+            validate,
+            error,
+        })
+    }
+}
+
+impl attr::ParseMultiple for StructAttribute {}
+
+/// Expansion of a macro for generating [`TryFrom`] implementations of a single-field
+/// struct.
+struct StructExpansion<'a> {
+    /// [`StructAttribute`] of the struct.
+    attr: StructAttribute,
+
+    /// [`syn::Ident`] of the struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    ident: &'a syn::Ident,
+
+    /// [`syn::Generics`] of the struct.
+    generics: &'a syn::Generics,
+
+    /// The single [`syn::Field`] of the struct.
+    field: &'a syn::Field,
+}
+
+impl ToTokens for StructExpansion<'_> {
+    /// Expands [`TryFrom`] implementations for a struct.
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let field_ty = &self.field.ty;
+        let validate = self.attr.validate.call(quote! { value });
+        let map_err = self.attr.error.as_ref().map(attr::Error::map_err);
+        let value = match &self.field.ident {
+            Some(field) => quote! { #ident { #field: value } },
+            None => quote! { #ident(value) },
+        };
+
+        for ty in &self.attr.types {
+            let error_ty = self.attr.error.as_ref().map_or_else(
+                || quote! { <#field_ty as derive_more::TryFrom<#ty>>::Error },
+                |err| err.ty.to_token_stream(),
+            );
+            // No conversion is needed for the field type itself, so no `Infallible` error
+            // is required to be mapped into the custom one.
+            let convert = if ty == field_ty {
+                quote! {}
+            } else {
+                quote! {
+                    let value = <#field_ty as derive_more::TryFrom<#ty>>::try_from(value)
+                        #map_err?;
+                }
+            };
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics derive_more::TryFrom<#ty> for #ident #ty_generics
+                     #where_clause
+                {
+                    type Error = #error_ty;
+
+                    #[inline]
+                    fn try_from(
+                        value: #ty,
+                    ) -> derive_more::core::result::Result<Self, Self::Error> {
+                        #convert
+                        #validate
+                        derive_more::core::result::Result::Ok(#value)
+                    }
+                }
+            }
+            .to_tokens(tokens);
+        }
+    }
+}
+
 /// Expansion of a macro for generating [`TryFrom`] implementation of an enum.
 struct Expansion {
     /// `#[repr(u/i*)]` of the enum.
@@ -119,23 +278,48 @@ impl ToTokens for Expansion {
             )
             .unzip();
 
-        quote! {
-            #[automatically_derived]
-            impl #impl_generics derive_more::TryFrom<#repr_ty #ty_generics> for #ident #where_clause {
-                type Error = derive_more::TryFromReprError<#repr_ty>;
-
-                #[allow(non_upper_case_globals)]
-                #[inline]
-                fn try_from(val: #repr_ty) -> derive_more::core::result::Result<Self, Self::Error> {
-                    #( const #consts: #repr_ty = #discriminants; )*
-                    match val {
-                        #(#consts => derive_more::core::result::Result::Ok(#ident::#variants),)*
-                        _ => derive_more::core::result::Result::Err(
-                            derive_more::TryFromReprError::new(val)
-                        ),
+        // Discriminants are matched directly, while other types are range-checked by
+        // converting into the discriminant type first.
+        let conversions = match &self.attr {
+            Some(ItemAttribute::Types(tys)) => tys
+                .0
+                .iter()
+                .map(|ty| {
+                    let scrutinee = quote! {
+                        <#repr_ty as derive_more::core::convert::TryFrom<#ty>>::try_from(val)
+                    };
+                    let consts = consts.iter().map(|c| {
+                        quote! { derive_more::core::result::Result::Ok(#c) }
+                    });
+                    (ty.to_token_stream(), scrutinee, consts.collect::<Vec<_>>())
+                })
+                .collect(),
+            _ => vec![(
+                repr_ty.to_token_stream(),
+                quote! { val },
+                consts.iter().map(ToTokens::to_token_stream).collect(),
+            )],
+        };
+
+        for (ty, scrutinee, patterns) in conversions {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics derive_more::TryFrom<#ty> for #ident #ty_generics #where_clause {
+                    type Error = derive_more::TryFromReprError<#ty>;
+
+                    #[allow(non_upper_case_globals)]
+                    #[inline]
+                    fn try_from(val: #ty) -> derive_more::core::result::Result<Self, Self::Error> {
+                        #( const #consts: #repr_ty = #discriminants; )*
+                        match #scrutinee {
+                            #(#patterns => derive_more::core::result::Result::Ok(#ident::#variants),)*
+                            _ => derive_more::core::result::Result::Err(
+                                derive_more::TryFromReprError::new(val)
+                            ),
+                        }
                     }
                 }
-            }
-        }.to_tokens(tokens);
+            }.to_tokens(tokens);
+        }
     }
 }
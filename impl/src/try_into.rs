@@ -1,10 +1,10 @@
 use crate::utils::{
-    add_extra_generic_param, numbered_vars, AttrParams, DeriveType, MultiFieldData,
-    State,
+    add_extra_generic_param, attr, numbered_vars, AttrParams, DeriveType,
+    MultiFieldData, State,
 };
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{DeriveInput, Result};
+use syn::{DeriveInput, Fields, Result};
 
 use crate::utils::HashMap;
 
@@ -16,7 +16,7 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         trait_name,
         "try_into".into(),
         AttrParams {
-            enum_: vec!["ignore", "owned", "ref", "ref_mut"],
+            enum_: vec!["ignore", "owned", "ref", "ref_mut", "error"],
             variant: vec!["ignore", "owned", "ref", "ref_mut"],
             struct_: vec!["ignore", "owned", "ref", "ref_mut"],
             field: vec!["ignore"],
@@ -27,6 +27,30 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         "Only enums can derive TryInto"
     );
 
+    let error = state
+        .default_info
+        .info
+        .error
+        .clone()
+        .map(syn::parse2::<attr::Error>)
+        .transpose()?;
+
+    let input_type = &input.ident;
+    let actual_variant_arms = state
+        .variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            let data_pattern = match variant.fields {
+                Fields::Named(_) => quote! { {..} },
+                Fields::Unnamed(_) => quote! { (..) },
+                Fields::Unit => quote! {},
+            };
+            let name = ident.to_string();
+            quote! { #input_type :: #ident #data_pattern => #name, }
+        })
+        .collect::<Vec<_>>();
+
     let mut variants_per_types = HashMap::default();
 
     for variant_state in state.enabled_variant_data().variant_states {
@@ -47,8 +71,6 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
     let mut tokens = TokenStream::new();
 
     for ((ref_type, ref original_types), ref multi_field_data) in variants_per_types {
-        let input_type = &input.ident;
-
         let pattern_ref = ref_type.pattern_ref();
         let lifetime = ref_type.lifetime();
         let reference_with_lifetime = ref_type.reference_with_lifetime();
@@ -80,15 +102,11 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
                 .collect::<Vec<_>>();
             format!("({})", types.join(", "))
         };
-        let variant_names = multi_field_data
-            .iter()
-            .map(|d| {
-                d.variant_name
-                    .expect("Somehow there was no variant name")
-                    .to_string()
-            })
-            .collect::<Vec<_>>()
-            .join(", ");
+        let expected_variants = multi_field_data.iter().map(|d| {
+            d.variant_name
+                .expect("Somehow there was no variant name")
+                .to_string()
+        });
 
         let generics_impl;
         let (_, ty_generics, where_clause) = input.generics.split_for_impl();
@@ -99,12 +117,31 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
             input.generics.split_for_impl()
         };
 
+        let default_error_ty = quote! {
+            derive_more::TryIntoError<#reference_with_lifetime #input_type #ty_generics>
+        };
+        let default_err = quote! {{
+            let variant_name = match value {
+                #( #actual_variant_arms )*
+            };
+            derive_more::TryIntoError::new(
+                value,
+                variant_name,
+                &[#( #expected_variants ),*],
+                #output_type,
+            )
+        }};
+        let (error_ty, err) = match &error {
+            Some(error) => (error.ty.to_token_stream(), error.convert(default_err)),
+            None => (default_error_ty, default_err),
+        };
+
         let try_from = quote! {
             #[automatically_derived]
             impl #impl_generics derive_more::core::convert::TryFrom<
                 #reference_with_lifetime #input_type #ty_generics
             > for (#(#reference_with_lifetime #original_types),*) #where_clause {
-                type Error = derive_more::TryIntoError<#reference_with_lifetime #input_type #ty_generics>;
+                type Error = #error_ty;
 
                 #[inline]
                 fn try_from(
@@ -112,9 +149,7 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
                 ) -> derive_more::core::result::Result<Self, Self::Error> {
                     match value {
                         #(#matchers)|* => derive_more::core::result::Result::Ok(#vars),
-                        _ => derive_more::core::result::Result::Err(
-                            derive_more::TryIntoError::new(value, #variant_names, #output_type),
-                        ),
+                        _ => derive_more::core::result::Result::Err(#err),
                     }
                 }
             }
@@ -1,8 +1,9 @@
-use crate::utils::{AttrParams, DeriveType, State};
+use crate::utils::{AttrParams, DeriveType, RefType, State, UnwrappedFields};
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{DeriveInput, Fields, Ident, Result, Type};
+use std::ptr;
+use syn::{DeriveInput, Fields, Ident, Result};
 
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
     let state = State::with_attr_params(
@@ -10,8 +11,8 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         trait_name,
         "try_unwrap".into(),
         AttrParams {
-            enum_: vec!["ignore", "owned", "ref", "ref_mut"],
-            variant: vec!["ignore", "owned", "ref", "ref_mut"],
+            enum_: vec!["ignore", "owned", "ref", "ref_mut", "option", "named"],
+            variant: vec!["ignore", "owned", "ref", "ref_mut", "option", "named"],
             struct_: vec!["ignore"],
             field: vec!["ignore"],
         },
@@ -26,6 +27,27 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
 
     let variant_data = state.enabled_variant_data();
 
+    // View structs are generated by `#[derive(Unwrap)]` already, if it requests them too.
+    // Errors of `#[unwrap(...)]` attributes are reported by `#[derive(Unwrap)]` itself.
+    #[cfg(feature = "unwrap")]
+    let unwrap_named = State::with_attr_params(
+        input,
+        "Unwrap",
+        "unwrap".into(),
+        crate::unwrap::attr_params(),
+    )
+    .map(|unwrap_state| unwrap_state.named_variants())
+    .unwrap_or_default();
+    #[cfg(not(feature = "unwrap"))]
+    let unwrap_named = Vec::<&syn::Variant>::new();
+    let view_structs = state
+        .named_variants()
+        .into_iter()
+        .filter(|variant| !unwrap_named.iter().any(|v| ptr::eq(*v, *variant)))
+        .map(|variant| {
+            UnwrappedFields::new(input, variant, true).view_structs(&input.vis)
+        });
+
     let mut funcs = vec![];
     for (variant_state, info) in
         Iterator::zip(variant_data.variant_states.iter(), variant_data.infos)
@@ -47,8 +69,24 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
             span = variant.ident.span(),
         );
         let variant_ident = &variant.ident;
-        let (data_pattern, ret_value, data_types) = get_field_info(&variant.fields);
+        let named = info
+            .info
+            .named
+            .or(state.default_info.info.named)
+            .unwrap_or_default();
+        let fields = UnwrappedFields::new(input, variant, named);
+        let data_pattern = fields.pattern();
         let pattern = quote! { #enum_name :: #variant_ident #data_pattern };
+        let (ret_value, ret_value_ref, ret_value_mut) = (
+            fields.value(RefType::No),
+            fields.value(RefType::Ref),
+            fields.value(RefType::Mut),
+        );
+        let (ret_ty, ret_ty_ref, ret_ty_mut) = (
+            fields.ty(RefType::No),
+            fields.ty(RefType::Ref),
+            fields.ty(RefType::Mut),
+        );
 
         let (failed_block, failed_block_ref, failed_block_mut) = (
             failed_block(&state, enum_name, &fn_name),
@@ -72,7 +110,7 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
             #[doc = #doc_owned]
             #[doc = #doc_else]
             pub fn #fn_name(self) -> derive_more::core::result::Result<
-                (#(#data_types),*), derive_more::TryUnwrapError<Self>
+                #ret_ty, derive_more::TryUnwrapError<Self>
             > {
                 match self {
                     #pattern => derive_more::core::result::Result::Ok(#ret_value),
@@ -87,10 +125,10 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
             #[doc = #doc_ref]
             #[doc = #doc_else]
             pub fn #ref_fn_name(&self) -> derive_more::core::result::Result<
-                (#(&#data_types),*), derive_more::TryUnwrapError<&Self>
+                #ret_ty_ref, derive_more::TryUnwrapError<&Self>
             > {
                 match self {
-                    #pattern => derive_more::core::result::Result::Ok(#ret_value),
+                    #pattern => derive_more::core::result::Result::Ok(#ret_value_ref),
                     val @ _ => #failed_block_ref,
                 }
             }
@@ -102,10 +140,10 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
             #[doc = #doc_mut]
             #[doc = #doc_else]
             pub fn #mut_fn_name(&mut self) -> derive_more::core::result::Result<
-                (#(&mut #data_types),*), derive_more::TryUnwrapError<&mut Self>
+                #ret_ty_mut, derive_more::TryUnwrapError<&mut Self>
             > {
                 match self {
-                    #pattern => derive_more::core::result::Result::Ok(#ret_value),
+                    #pattern => derive_more::core::result::Result::Ok(#ret_value_mut),
                     val @ _ => #failed_block_mut,
                 }
             }
@@ -120,9 +158,89 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         if info.ref_mut && state.default_info.ref_mut {
             funcs.push(mut_func);
         }
+
+        if !info
+            .info
+            .option
+            .or(state.default_info.info.option)
+            .unwrap_or_default()
+        {
+            continue;
+        }
+
+        let snake_ident = variant.ident.to_string().to_case(Case::Snake);
+        let into_fn_name =
+            format_ident!("into_{snake_ident}", span = variant.ident.span());
+        let as_fn_name = format_ident!("as_{snake_ident}", span = variant.ident.span());
+        let as_mut_fn_name =
+            format_ident!("as_{snake_ident}_mut", span = variant.ident.span());
+
+        let doc_owned = format!(
+            "Converts this value into the fields of the `{enum_name}::{variant_ident}` \
+             variant.\n",
+        );
+        let doc_ref = format!(
+            "Converts this reference into references to the fields of the \
+             `{enum_name}::{variant_ident}` variant.\n",
+        );
+        let doc_mut = format!(
+            "Converts this mutable reference into mutable references to the fields of the \
+             `{enum_name}::{variant_ident}` variant.\n",
+        );
+        let doc_else = "Returns [`None`] if this value is of any other type.";
+
+        if info.owned && state.default_info.owned {
+            funcs.push(quote! {
+                #[inline]
+                #[must_use]
+                #[doc = #doc_owned]
+                #[doc = #doc_else]
+                pub fn #into_fn_name(self) -> derive_more::core::option::Option<#ret_ty> {
+                    match self {
+                        #pattern => derive_more::core::option::Option::Some(#ret_value),
+                        #[allow(unreachable_patterns)]
+                        _ => derive_more::core::option::Option::None,
+                    }
+                }
+            });
+        }
+        if info.ref_ && state.default_info.ref_ {
+            funcs.push(quote! {
+                #[inline]
+                #[must_use]
+                #[doc = #doc_ref]
+                #[doc = #doc_else]
+                pub fn #as_fn_name(&self) -> derive_more::core::option::Option<#ret_ty_ref> {
+                    match self {
+                        #pattern => derive_more::core::option::Option::Some(#ret_value_ref),
+                        #[allow(unreachable_patterns)]
+                        _ => derive_more::core::option::Option::None,
+                    }
+                }
+            });
+        }
+        if info.ref_mut && state.default_info.ref_mut {
+            funcs.push(quote! {
+                #[inline]
+                #[must_use]
+                #[doc = #doc_mut]
+                #[doc = #doc_else]
+                pub fn #as_mut_fn_name(&mut self) -> derive_more::core::option::Option<
+                    #ret_ty_mut
+                > {
+                    match self {
+                        #pattern => derive_more::core::option::Option::Some(#ret_value_mut),
+                        #[allow(unreachable_patterns)]
+                        _ => derive_more::core::option::Option::None,
+                    }
+                }
+            });
+        }
     }
 
     let imp = quote! {
+        #(#view_structs)*
+
         #[automatically_derived]
         impl #imp_generics #enum_name #type_generics #where_clause {
             #(#funcs)*
@@ -132,22 +250,6 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
     Ok(imp)
 }
 
-fn get_field_info(fields: &Fields) -> (TokenStream, TokenStream, Vec<&Type>) {
-    match fields {
-        Fields::Named(_) => panic!("cannot unwrap anonymous records"),
-        Fields::Unnamed(ref fields) => {
-            let (idents, types) = fields
-                .unnamed
-                .iter()
-                .enumerate()
-                .map(|(n, it)| (format_ident!("field_{n}"), &it.ty))
-                .unzip::<_, _, Vec<_>, Vec<_>>();
-            (quote! { (#(#idents),*) }, quote! { (#(#idents),*) }, types)
-        }
-        Fields::Unit => (quote! {}, quote! { () }, vec![]),
-    }
-}
-
 fn failed_block(state: &State, enum_name: &Ident, func_name: &Ident) -> TokenStream {
     let arms = state
         .variant_states
@@ -1,21 +1,22 @@
-use crate::utils::{AttrParams, DeriveType, State};
+use crate::utils::{AttrParams, DeriveType, RefType, State, UnwrappedFields};
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{DeriveInput, Fields, Ident, Result, Type};
+use syn::{DeriveInput, Fields, Ident, Result};
+
+/// Returns the [`AttrParams`] of the `#[unwrap(...)]` attribute.
+pub(crate) fn attr_params() -> AttrParams {
+    AttrParams {
+        enum_: vec!["ignore", "owned", "ref", "ref_mut", "named"],
+        variant: vec!["ignore", "owned", "ref", "ref_mut", "named", "rename"],
+        struct_: vec!["ignore"],
+        field: vec!["ignore"],
+    }
+}
 
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
-    let state = State::with_attr_params(
-        input,
-        trait_name,
-        "unwrap".into(),
-        AttrParams {
-            enum_: vec!["ignore", "owned", "ref", "ref_mut"],
-            variant: vec!["ignore", "owned", "ref", "ref_mut"],
-            struct_: vec!["ignore"],
-            field: vec!["ignore"],
-        },
-    )?;
+    let state =
+        State::with_attr_params(input, trait_name, "unwrap".into(), attr_params())?;
     assert!(
         state.derive_type == DeriveType::Enum,
         "Unwrap can only be derived for enums",
@@ -26,98 +27,104 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
 
     let variant_data = state.enabled_variant_data();
 
+    let view_structs = state.named_variants().into_iter().map(|variant| {
+        UnwrappedFields::new(input, variant, true).view_structs(&input.vis)
+    });
+
     let mut funcs = vec![];
     for (variant_state, info) in
         Iterator::zip(variant_data.variant_states.iter(), variant_data.infos)
     {
         let variant = variant_state.variant.unwrap();
-        let fn_name = format_ident!(
-            "unwrap_{ident}",
-            ident = variant.ident.to_string().to_case(Case::Snake),
-            span = variant.ident.span(),
-        );
-        let ref_fn_name = format_ident!(
-            "unwrap_{ident}_ref",
-            ident = variant.ident.to_string().to_case(Case::Snake),
-            span = variant.ident.span(),
-        );
-        let mut_fn_name = format_ident!(
-            "unwrap_{ident}_mut",
-            ident = variant.ident.to_string().to_case(Case::Snake),
-            span = variant.ident.span(),
-        );
         let variant_ident = &variant.ident;
-        let (data_pattern, ret_value, data_types) = get_field_info(&variant.fields);
+        let name = info.info.rename.clone().unwrap_or_else(|| {
+            format_ident!(
+                "{}",
+                variant_ident.to_string().to_case(Case::Snake),
+                span = variant_ident.span(),
+            )
+        });
+        let named = info
+            .info
+            .named
+            .or(state.default_info.info.named)
+            .unwrap_or_default();
+        let fields = UnwrappedFields::new(input, variant, named);
+        let data_pattern = fields.pattern();
         let pattern = quote! { #enum_name :: #variant_ident #data_pattern };
 
-        let (failed_block, failed_block_ref, failed_block_mut) = (
-            failed_block(&state, enum_name, &fn_name),
-            failed_block(&state, enum_name, &ref_fn_name),
-            failed_block(&state, enum_name, &mut_fn_name),
-        );
-
-        let doc_owned = format!(
-            "Unwraps this value to the `{enum_name}::{variant_ident}` variant.\n",
-        );
-        let doc_ref = format!(
-            "Unwraps this reference to the `{enum_name}::{variant_ident}` variant.\n",
-        );
-        let doc_mut = format!(
-            "Unwraps this mutable reference to the `{enum_name}::{variant_ident}` variant.\n",
-        );
-        let doc_else = "Panics if this value is of any other type.";
-
-        let func = quote! {
-            #[inline]
-            #[track_caller]
-            #[doc = #doc_owned]
-            #[doc = #doc_else]
-            pub fn #fn_name(self) -> (#(#data_types),*) {
-                match self {
-                    #pattern => #ret_value,
-                    val @ _ => #failed_block,
-                }
+        for (ref_type, enabled) in [
+            (RefType::No, info.owned && state.default_info.owned),
+            (RefType::Ref, info.ref_ && state.default_info.ref_),
+            (RefType::Mut, info.ref_mut && state.default_info.ref_mut),
+        ] {
+            if !enabled {
+                continue;
             }
-        };
-
-        let ref_func = quote! {
-            #[inline]
-            #[track_caller]
-            #[doc = #doc_ref]
-            #[doc = #doc_else]
-            pub fn #ref_fn_name(&self) -> (#(&#data_types),*) {
-                match self {
-                    #pattern => #ret_value,
-                    val @ _ => #failed_block_ref,
-                }
-            }
-        };
-
-        let mut_func = quote! {
-            #[inline]
-            #[track_caller]
-            #[doc = #doc_mut]
-            #[doc = #doc_else]
-            pub fn #mut_fn_name(&mut self) -> (#(&mut #data_types),*) {
-                match self {
-                    #pattern => #ret_value,
-                    val @ _ => #failed_block_mut,
+
+            let suffix = match ref_type {
+                RefType::No => "",
+                RefType::Ref => "_ref",
+                RefType::Mut => "_mut",
+            };
+            let fn_name = format_ident!("unwrap_{name}{suffix}", span = name.span());
+            let expect_fn_name =
+                format_ident!("expect_{name}{suffix}", span = name.span());
+            let receiver = match ref_type {
+                RefType::No => quote! { self },
+                RefType::Ref => quote! { &self },
+                RefType::Mut => quote! { &mut self },
+            };
+            let ret_value = fields.value(ref_type);
+            let ret_ty = fields.ty(ref_type);
+            let failed_block = failed_block(&state, enum_name, &fn_name);
+
+            let doc = match ref_type {
+                RefType::No => format!(
+                    "Unwraps this value to the `{enum_name}::{variant_ident}` variant.\n",
+                ),
+                RefType::Ref => format!(
+                    "Unwraps this reference to the `{enum_name}::{variant_ident}` variant.\n",
+                ),
+                RefType::Mut => format!(
+                    "Unwraps this mutable reference to the `{enum_name}::{variant_ident}` \
+                     variant.\n",
+                ),
+            };
+            let doc_else = "Panics if this value is of any other type.";
+            let doc_expect_else =
+                "Panics with the provided `msg` if this value is of any other type.";
+
+            funcs.push(quote! {
+                #[inline]
+                #[track_caller]
+                #[doc = #doc]
+                #[doc = #doc_else]
+                pub fn #fn_name(#receiver) -> #ret_ty {
+                    match self {
+                        #pattern => #ret_value,
+                        val @ _ => #failed_block,
+                    }
                 }
-            }
-        };
 
-        if info.owned && state.default_info.owned {
-            funcs.push(func);
-        }
-        if info.ref_ && state.default_info.ref_ {
-            funcs.push(ref_func);
-        }
-        if info.ref_mut && state.default_info.ref_mut {
-            funcs.push(mut_func);
+                #[inline]
+                #[track_caller]
+                #[doc = #doc]
+                #[doc = #doc_expect_else]
+                pub fn #expect_fn_name(#receiver, msg: &str) -> #ret_ty {
+                    match self {
+                        #pattern => #ret_value,
+                        #[allow(unreachable_patterns)]
+                        _ => derive_more::core::panic!("{}", msg),
+                    }
+                }
+            });
         }
     }
 
     let imp = quote! {
+        #(#view_structs)*
+
         #[automatically_derived]
         impl #imp_generics #enum_name #type_generics #where_clause {
             #(#funcs)*
@@ -127,22 +134,6 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
     Ok(imp)
 }
 
-fn get_field_info(fields: &Fields) -> (TokenStream, TokenStream, Vec<&Type>) {
-    match fields {
-        Fields::Named(_) => panic!("cannot unwrap anonymous records"),
-        Fields::Unnamed(ref fields) => {
-            let (idents, types) = fields
-                .unnamed
-                .iter()
-                .enumerate()
-                .map(|(n, it)| (format_ident!("field_{n}"), &it.ty))
-                .unzip::<_, _, Vec<_>, Vec<_>>();
-            (quote! { (#(#idents),*) }, quote! { (#(#idents),*) }, types)
-        }
-        Fields::Unit => (quote! {}, quote! { () }, vec![]),
-    }
-}
-
 fn failed_block(state: &State, enum_name: &Ident, fn_name: &Ident) -> TokenStream {
     let arms = state
         .variant_states
@@ -8,32 +8,58 @@ use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Data,
     DeriveInput, Error, Field, Fields, FieldsNamed, FieldsUnnamed, GenericParam,
-    Generics, Ident, ImplGenerics, Index, Result, Token, Type, TypeGenerics,
-    TypeParamBound, Variant, WhereClause,
+    Generics, Ident, Index, Result, Token, Type, TypeGenerics, TypeParamBound, Variant,
+    WhereClause,
 };
 
 #[cfg(any(
+    feature = "add",
+    feature = "add_assign",
     feature = "as_ref",
+    feature = "constructor",
     feature = "debug",
     feature = "display",
     feature = "from",
+    feature = "from_str",
     feature = "into",
+    feature = "is_variant",
+    feature = "kind",
+    feature = "mul",
+    feature = "mul_assign",
+    feature = "not",
+    feature = "sum",
+    feature = "variants",
     feature = "try_from",
+    feature = "try_into",
 ))]
 pub(crate) use self::either::Either;
 #[cfg(any(feature = "from", feature = "into"))]
 pub(crate) use self::fields_ext::FieldsExt;
-#[cfg(feature = "as_ref")]
+#[cfg(any(feature = "as_ref", feature = "try_unwrap", feature = "unwrap"))]
 pub(crate) use self::generics_search::GenericsSearch;
 #[cfg(any(
+    feature = "add",
+    feature = "add_assign",
     feature = "as_ref",
+    feature = "constructor",
     feature = "debug",
     feature = "display",
     feature = "from",
+    feature = "from_str",
     feature = "into",
+    feature = "is_variant",
+    feature = "kind",
+    feature = "mul",
+    feature = "mul_assign",
+    feature = "not",
+    feature = "sum",
+    feature = "variants",
     feature = "try_from",
+    feature = "try_into",
 ))]
 pub(crate) use self::spanning::Spanning;
+#[cfg(any(feature = "try_unwrap", feature = "unwrap"))]
+pub(crate) use self::unwrapped_fields::UnwrappedFields;
 
 #[derive(Clone, Copy, Default)]
 pub struct DeterministicState;
@@ -125,16 +151,6 @@ pub fn field_idents<'a>(fields: &'a [&'a Field]) -> Vec<&'a Ident> {
         .collect()
 }
 
-pub fn get_field_types_iter<'a>(
-    fields: &'a [&'a Field],
-) -> Box<dyn Iterator<Item = &'a Type> + 'a> {
-    Box::new(fields.iter().map(|f| &f.ty))
-}
-
-pub fn get_field_types<'a>(fields: &'a [&'a Field]) -> Vec<&'a Type> {
-    get_field_types_iter(fields).collect()
-}
-
 pub fn add_extra_type_param_bound_op_output<'a>(
     generics: &'a Generics,
     trait_ident: &'a Ident,
@@ -292,25 +308,9 @@ impl AttrParams {
             field: params,
         }
     }
-    pub fn struct_(params: Vec<&'static str>) -> AttrParams {
-        AttrParams {
-            enum_: vec![],
-            struct_: params,
-            variant: vec![],
-            field: vec![],
-        }
-    }
 }
 
 impl<'input> State<'input> {
-    pub fn new<'arg_input>(
-        input: &'arg_input DeriveInput,
-        trait_name: &'static str,
-        trait_attr: String,
-    ) -> Result<State<'arg_input>> {
-        State::new_impl(input, trait_name, trait_attr, AttrParams::default(), true)
-    }
-
     pub fn with_field_ignore<'arg_input>(
         input: &'arg_input DeriveInput,
         trait_name: &'static str,
@@ -577,10 +577,7 @@ impl<'input> State<'input> {
             trait_path: data.trait_path,
             trait_path_with_params: data.trait_path_with_params.clone(),
             casted_trait: data.casted_traits[0].clone(),
-            impl_generics: data.impl_generics.clone(),
             ty_generics: data.ty_generics.clone(),
-            where_clause: data.where_clause,
-            multi_field_data: data,
         }
     }
 
@@ -608,7 +605,7 @@ impl<'input> State<'input> {
             .iter()
             .map(|field_type| quote! { <#field_type as #trait_path_with_params> })
             .collect();
-        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
         let input_type = &self.input.ident;
         let (variant_name, variant_type) = self.variant.map_or_else(
             || (None, quote! { #input_type }),
@@ -632,9 +629,7 @@ impl<'input> State<'input> {
             trait_path,
             trait_path_with_params,
             casted_traits,
-            impl_generics,
             ty_generics,
-            where_clause,
             state: self,
         }
     }
@@ -653,6 +648,29 @@ impl<'input> State<'input> {
         }
     }
 
+    /// Returns the variants with named fields, requested to be returned in view structs
+    /// with a `named` argument, placed on the enum or the variant.
+    ///
+    /// Unlike the [`State::enabled_variant_data()`], it doesn't depend on which variants are
+    /// enabled, so the view structs are defined exactly once, even when shared by multiple
+    /// derives.
+    #[cfg(any(feature = "try_unwrap", feature = "unwrap"))]
+    pub fn named_variants(&self) -> Vec<&'input Variant> {
+        self.variants
+            .iter()
+            .zip(&self.full_meta_infos)
+            .filter(|(variant, info)| {
+                matches!(variant.fields, Fields::Named(_))
+                    && info
+                        .info
+                        .named
+                        .or(self.default_info.info.named)
+                        .unwrap_or_default()
+            })
+            .map(|(variant, _)| *variant)
+            .collect()
+    }
+
     fn enabled_variants(&self) -> Vec<&'input Variant> {
         self.variants
             .iter()
@@ -736,10 +754,7 @@ pub struct SingleFieldData<'input, 'state> {
     pub trait_path: &'state TokenStream,
     pub trait_path_with_params: TokenStream,
     pub casted_trait: TokenStream,
-    pub impl_generics: ImplGenerics<'state>,
     pub ty_generics: TypeGenerics<'state>,
-    pub where_clause: Option<&'state WhereClause>,
-    multi_field_data: MultiFieldData<'input, 'state>,
 }
 
 #[derive(Clone)]
@@ -758,9 +773,7 @@ pub struct MultiFieldData<'input, 'state> {
     pub trait_path: &'state TokenStream,
     pub trait_path_with_params: TokenStream,
     pub casted_traits: Vec<TokenStream>,
-    pub impl_generics: ImplGenerics<'state>,
     pub ty_generics: TypeGenerics<'state>,
-    pub where_clause: Option<&'state WhereClause>,
     pub state: &'state State<'input>,
 }
 
@@ -804,12 +817,6 @@ impl<'input, 'state> MultiFieldData<'input, 'state> {
     }
 }
 
-impl<'input, 'state> SingleFieldData<'input, 'state> {
-    pub fn initializer<T: ToTokens>(&self, initializers: &[T]) -> TokenStream {
-        self.multi_field_data.initializer(initializers)
-    }
-}
-
 fn get_meta_info(
     trait_attr: &str,
     attrs: &[Attribute],
@@ -866,12 +873,22 @@ fn get_meta_info(
         }
     };
 
-    parse_punctuated_nested_meta(
-        &mut info,
-        &list.parse_args_with(Punctuated::parse_terminated)?,
-        allowed_attr_params,
-        None,
-    )?;
+    let metas = list.parse_args_with(Punctuated::parse_terminated)?;
+    parse_punctuated_nested_meta(&mut info, &metas, allowed_attr_params, None)?;
+
+    // Parameters customizing only the shape of the generated code don't enable it explicitly,
+    // otherwise all the other variants or fields would be disabled by default.
+    if !metas.is_empty()
+        && metas.iter().all(|meta| match meta {
+            polyfill::Meta::Path(path) => ["named", "copy", "mut", "into"]
+                .iter()
+                .any(|param| path.is_ident(param)),
+            polyfill::Meta::NameValue(nv) => nv.path.is_ident("rename"),
+            polyfill::Meta::List(_) => false,
+        })
+    {
+        info.enabled = None;
+    }
 
     Ok(info)
 }
@@ -979,6 +996,17 @@ fn parse_punctuated_nested_meta(
                         }
                     }
 
+                    #[cfg(feature = "try_into")]
+                    (None, "error") => {
+                        parse_nested = false;
+                        if info.error.replace(meta.to_token_stream()).is_some() {
+                            return Err(Error::new(
+                                list.span(),
+                                "Only a single `error(...)` parameter is allowed",
+                            ));
+                        }
+                    }
+
                     _ => {
                         return Err(Error::new(
                             list.span(),
@@ -1014,16 +1042,64 @@ fn parse_punctuated_nested_meta(
 
                 let attr_name = path.get_ident().unwrap().to_string();
                 match (wrapper_name, attr_name.as_str()) {
-                    (None, "ignore") => info.enabled = Some(false),
+                    (None, "ignore") | (None, "skip") => info.enabled = Some(false),
                     (None, "forward") => info.forward = Some(true),
                     (Some("not"), "forward") => info.forward = Some(false),
                     (None, "owned") => info.owned = Some(true),
                     (None, "ref") => info.ref_ = Some(true),
                     (None, "ref_mut") => info.ref_mut = Some(true),
+                    (None, "copy") => info.owned = Some(true),
+                    (None, "mut") => info.ref_mut = Some(true),
+                    (None, "into") => info.into = Some(true),
                     (None, "source") => info.source = Some(true),
                     (Some("not"), "source") => info.source = Some(false),
                     (None, "backtrace") => info.backtrace = Some(true),
                     (Some("not"), "backtrace") => info.backtrace = Some(false),
+                    (None, "option") => info.option = Some(true),
+                    (None, "named") => info.named = Some(true),
+                    // Argument of `skip`, parsed by `add_helpers::Skips` separately.
+                    (None, "default") => {}
+                    _ => {
+                        return Err(Error::new(
+                            path.span(),
+                            format!(
+                                "Attribute doesn't support parameter `{}` here",
+                                quote! { #path }
+                            ),
+                        ))
+                    }
+                }
+            }
+
+            polyfill::Meta::NameValue(nv) => {
+                let path = &nv.path;
+                if !allowed_attr_params.iter().any(|param| path.is_ident(param)) {
+                    return Err(Error::new(
+                        meta.span(),
+                        format!(
+                            "Attribute parameter not supported. \
+                             Supported attribute parameters are: {}",
+                            allowed_attr_params.join(", "),
+                        ),
+                    ));
+                }
+
+                let attr_name = path.get_ident().unwrap().to_string();
+                match (wrapper_name, attr_name.as_str()) {
+                    (None, "rename") => {
+                        let syn::Lit::Str(lit) = &nv.value else {
+                            return Err(Error::new(
+                                nv.value.span(),
+                                "Attribute parameter `rename` expects a string literal",
+                            ));
+                        };
+                        let ident = lit.parse::<syn::Ident>().map_err(|_| {
+                            Error::new(lit.span(), "Expected a valid identifier")
+                        })?;
+                        info.rename = Some(ident);
+                    }
+                    // Argument of `skip`, parsed by `add_helpers::Skips` separately.
+                    (None, "keep") => {}
                     _ => {
                         return Err(Error::new(
                             path.span(),
@@ -1137,10 +1213,26 @@ pub(crate) mod polyfill {
         }
     }
 
+    #[derive(Clone)]
+    pub(crate) struct MetaNameValue {
+        pub(crate) path: PathOrKeyword,
+        pub(crate) eq_token: Token![=],
+        pub(crate) value: syn::Lit,
+    }
+
+    impl ToTokens for MetaNameValue {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.path.to_tokens(tokens);
+            self.eq_token.to_tokens(tokens);
+            self.value.to_tokens(tokens);
+        }
+    }
+
     #[derive(Clone)]
     pub(crate) enum Meta {
         Path(PathOrKeyword),
         List(MetaList),
+        NameValue(MetaNameValue),
     }
 
     impl Parse for Meta {
@@ -1153,6 +1245,12 @@ pub(crate) mod polyfill {
                     path,
                     tokens: tokens.parse()?,
                 })
+            } else if input.peek(Token![=]) {
+                Self::NameValue(MetaNameValue {
+                    path,
+                    eq_token: input.parse()?,
+                    value: input.parse()?,
+                })
             } else {
                 Self::Path(path)
             })
@@ -1164,6 +1262,7 @@ pub(crate) mod polyfill {
             match self {
                 Self::Path(p) => p.to_tokens(tokens),
                 Self::List(l) => l.to_tokens(tokens),
+                Self::NameValue(nv) => nv.to_tokens(tokens),
             }
         }
     }
@@ -1219,6 +1318,12 @@ pub struct MetaInfo {
     pub ref_mut: Option<bool>,
     pub source: Option<bool>,
     pub backtrace: Option<bool>,
+    pub option: Option<bool>,
+    pub named: Option<bool>,
+    pub into: Option<bool>,
+    pub rename: Option<syn::Ident>,
+    #[cfg(feature = "try_into")]
+    pub error: Option<TokenStream>,
     #[cfg(any(feature = "from", feature = "into"))]
     pub types: HashMap<RefType, HashSet<syn::Type>>,
 }
@@ -1308,12 +1413,24 @@ pub fn is_type_parameter_used_in_type(
 }
 
 #[cfg(any(
+    feature = "add",
+    feature = "add_assign",
     feature = "as_ref",
+    feature = "constructor",
     feature = "debug",
     feature = "display",
     feature = "from",
+    feature = "from_str",
     feature = "into",
+    feature = "is_variant",
+    feature = "kind",
+    feature = "mul",
+    feature = "mul_assign",
+    feature = "not",
+    feature = "sum",
+    feature = "variants",
     feature = "try_from",
+    feature = "try_into",
 ))]
 mod either {
     use proc_macro2::TokenStream;
@@ -1379,12 +1496,24 @@ mod either {
 }
 
 #[cfg(any(
+    feature = "add",
+    feature = "add_assign",
     feature = "as_ref",
+    feature = "constructor",
     feature = "debug",
     feature = "display",
     feature = "from",
+    feature = "from_str",
     feature = "into",
+    feature = "is_variant",
+    feature = "kind",
+    feature = "mul",
+    feature = "mul_assign",
+    feature = "not",
+    feature = "sum",
+    feature = "variants",
     feature = "try_from",
+    feature = "try_into",
 ))]
 mod spanning {
     use std::ops::{Deref, DerefMut};
@@ -1474,12 +1603,24 @@ mod spanning {
 }
 
 #[cfg(any(
+    feature = "add",
+    feature = "add_assign",
     feature = "as_ref",
+    feature = "constructor",
     feature = "debug",
     feature = "display",
     feature = "from",
+    feature = "from_str",
     feature = "into",
+    feature = "is_variant",
+    feature = "kind",
+    feature = "mul",
+    feature = "mul_assign",
+    feature = "not",
+    feature = "sum",
+    feature = "variants",
     feature = "try_from",
+    feature = "try_into",
 ))]
 pub(crate) mod attr {
     use std::any::Any;
@@ -1498,6 +1639,10 @@ pub(crate) mod attr {
         feature = "try_from"
     ))]
     pub(crate) use self::empty::Empty;
+    #[cfg(any(feature = "from_str", feature = "try_from", feature = "try_into"))]
+    pub(crate) use self::error::Error;
+    #[cfg(any(feature = "from_str", feature = "variants"))]
+    pub(crate) use self::rename_all::RenameAll;
     #[cfg(any(
         feature = "as_ref",
         feature = "debug",
@@ -1505,13 +1650,20 @@ pub(crate) mod attr {
         feature = "into",
     ))]
     pub(crate) use self::skip::Skip;
-    #[cfg(any(feature = "as_ref", feature = "from", feature = "try_from"))]
+    #[cfg(any(
+        feature = "as_ref",
+        feature = "from",
+        feature = "into",
+        feature = "try_from"
+    ))]
     pub(crate) use self::types::Types;
+    #[cfg(any(feature = "from_str", feature = "try_from"))]
+    pub(crate) use self::validate::Validate;
     #[cfg(any(feature = "as_ref", feature = "from"))]
     pub(crate) use self::{
         conversion::Conversion, field_conversion::FieldConversion, forward::Forward,
     };
-    #[cfg(feature = "try_from")]
+    #[cfg(any(feature = "into", feature = "try_from"))]
     pub(crate) use self::{repr_conversion::ReprConversion, repr_int::ReprInt};
 
     /// [`Parse`]ing with additional state or metadata.
@@ -1706,6 +1858,136 @@ pub(crate) mod attr {
         }
     }
 
+    #[cfg(any(feature = "from_str", feature = "try_from", feature = "try_into"))]
+    mod error {
+        use proc_macro2::TokenStream;
+        use quote::quote;
+        use syn::{
+            parse::{Parse, ParseStream},
+            spanned::Spanned as _,
+            token,
+        };
+
+        use super::ParseMultiple;
+
+        /// Representation of an `error` attribute, specifying a custom error type along
+        /// with an optional function mapping the original error into it.
+        ///
+        /// ```rust,ignore
+        /// #[<attribute>(error(<ty>))]
+        /// #[<attribute>(error(<ty>, <map-fn>))]
+        /// ```
+        pub(crate) struct Error {
+            /// Custom error type.
+            pub(crate) ty: syn::Type,
+
+            /// Path to the function mapping the original error into the custom one.
+            ///
+            /// [`From`] conversion is used if [`None`].
+            pub(crate) map: Option<syn::Path>,
+        }
+
+        impl Parse for Error {
+            fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+                let path = input.parse::<syn::Path>()?;
+                if !path.is_ident("error") {
+                    return Err(syn::Error::new(
+                        path.span(),
+                        "only `error` allowed here",
+                    ));
+                }
+
+                let inner;
+                syn::parenthesized!(inner in input);
+                let ty = inner.parse()?;
+                let map = if inner.is_empty() {
+                    None
+                } else {
+                    inner.parse::<token::Comma>()?;
+                    (!inner.is_empty()).then(|| inner.parse()).transpose()?
+                };
+                if !inner.is_empty() {
+                    return Err(inner
+                        .error("only error type and mapping function allowed here"));
+                }
+                Ok(Self { ty, map })
+            }
+        }
+
+        impl ParseMultiple for Error {}
+
+        impl Error {
+            /// Generates an expression converting the provided original `error` expression
+            /// into the custom error type.
+            pub(crate) fn convert(&self, error: TokenStream) -> TokenStream {
+                let ty = &self.ty;
+                match &self.map {
+                    Some(map) => quote! { #map(#error) },
+                    None => quote! {
+                        <#ty as derive_more::core::convert::From<_>>::from(#error)
+                    },
+                }
+            }
+
+            /// Generates a `.map_err()` call converting the original error of a [`Result`]
+            /// into the custom error type.
+            #[cfg(any(feature = "from_str", feature = "try_from"))]
+            pub(crate) fn map_err(&self) -> TokenStream {
+                let convert = self.convert(quote! { err });
+                quote! { .map_err(|err| #convert) }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "from_str", feature = "try_from"))]
+    mod validate {
+        use proc_macro2::TokenStream;
+        use quote::{quote, ToTokens};
+        use syn::{
+            parse::{Parse, ParseStream},
+            spanned::Spanned as _,
+            token,
+        };
+
+        use super::ParseMultiple;
+
+        /// Representation of a `validate` attribute, specifying the function validating the
+        /// value of a field.
+        ///
+        /// The function is expected to have a `fn(&<field-type>) -> Result<(), <error>>`
+        /// signature.
+        ///
+        /// ```rust,ignore
+        /// #[<attribute>(validate = <fn-path>)]
+        /// ```
+        pub(crate) struct Validate(pub(crate) syn::Path);
+
+        impl Parse for Validate {
+            fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+                let path = input.parse::<syn::Path>()?;
+                if !path.is_ident("validate") {
+                    return Err(syn::Error::new(
+                        path.span(),
+                        "only `validate` allowed here",
+                    ));
+                }
+                input.parse::<token::Eq>()?;
+                input.parse().map(Self)
+            }
+        }
+
+        impl ParseMultiple for Validate {}
+
+        impl Validate {
+            /// Generates a statement calling the validation function with the provided
+            /// `value`, and returning its error (converted via [`From`]), if any.
+            pub(crate) fn call(&self, value: impl ToTokens) -> TokenStream {
+                let validate = &self.0;
+                quote! { #validate(&#value)?; }
+            }
+        }
+    }
+
     #[cfg(any(feature = "as_ref", feature = "from"))]
     mod forward {
         use syn::{
@@ -1735,7 +2017,7 @@ pub(crate) mod attr {
         impl ParseMultiple for Forward {}
     }
 
-    #[cfg(feature = "try_from")]
+    #[cfg(any(feature = "into", feature = "try_from"))]
     mod repr_int {
         use proc_macro2::Span;
         use syn::parse::{Parse, ParseStream};
@@ -1829,6 +2111,85 @@ pub(crate) mod attr {
         }
     }
 
+    #[cfg(any(feature = "from_str", feature = "variants"))]
+    mod rename_all {
+        use convert_case::{Case, Casing as _};
+        use syn::{
+            parse::{Parse, ParseStream},
+            spanned::Spanned as _,
+            token,
+        };
+
+        use super::ParseMultiple;
+
+        /// Representation of a `rename_all` attribute, specifying the casing to convert
+        /// identifiers into.
+        ///
+        /// ```rust,ignore
+        /// #[<attribute>(rename_all = "<casing>")]
+        /// ```
+        ///
+        /// Supported casings are the ones of [`RenameAll::CASINGS`].
+        #[derive(Clone, Copy, Debug)]
+        pub(crate) struct RenameAll(Case);
+
+        impl RenameAll {
+            /// Names of the supported casings along with their [`Case`]s.
+            const CASINGS: &'static [(&'static str, Case)] = &[
+                ("lowercase", Case::Flat),
+                ("UPPERCASE", Case::UpperFlat),
+                ("PascalCase", Case::Pascal),
+                ("camelCase", Case::Camel),
+                ("snake_case", Case::Snake),
+                ("SCREAMING_SNAKE_CASE", Case::UpperSnake),
+                ("kebab-case", Case::Kebab),
+                ("SCREAMING-KEBAB-CASE", Case::UpperKebab),
+            ];
+
+            /// Converts the provided [`syn::Ident`] into the casing of this [`RenameAll`].
+            ///
+            /// [`syn::Ident`]: struct@syn::Ident
+            pub(crate) fn convert(&self, ident: &syn::Ident) -> String {
+                ident.to_string().to_case(self.0)
+            }
+        }
+
+        impl Parse for RenameAll {
+            fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+                let path = input.parse::<syn::Path>()?;
+                if !path.is_ident("rename_all") {
+                    return Err(syn::Error::new(
+                        path.span(),
+                        "only `rename_all` allowed here",
+                    ));
+                }
+                input.parse::<token::Eq>()?;
+
+                let casing = input.parse::<syn::LitStr>()?;
+                Self::CASINGS
+                    .iter()
+                    .find_map(|(name, case)| {
+                        (casing.value() == *name).then_some(Self(*case))
+                    })
+                    .ok_or_else(|| {
+                        syn::Error::new(
+                            casing.span(),
+                            format!(
+                                "unexpected casing, expected one of: {}",
+                                Self::CASINGS
+                                    .iter()
+                                    .map(|(name, _)| format!("`{name}`"))
+                                    .collect::<Vec<_>>()
+                                    .join(", "),
+                            ),
+                        )
+                    })
+            }
+        }
+
+        impl ParseMultiple for RenameAll {}
+    }
+
     #[cfg(any(
         feature = "as_ref",
         feature = "debug",
@@ -1890,7 +2251,12 @@ pub(crate) mod attr {
         }
     }
 
-    #[cfg(any(feature = "as_ref", feature = "from", feature = "try_from"))]
+    #[cfg(any(
+        feature = "as_ref",
+        feature = "from",
+        feature = "into",
+        feature = "try_from"
+    ))]
     mod types {
         use syn::{
             parse::{Parse, ParseStream},
@@ -2097,7 +2463,7 @@ pub(crate) mod attr {
         }
     }
 
-    #[cfg(feature = "try_from")]
+    #[cfg(any(feature = "into", feature = "try_from"))]
     mod repr_conversion {
         use syn::parse::{Parse, ParseStream};
 
@@ -2287,7 +2653,7 @@ mod fields_ext {
     impl<T: Len + ?Sized> FieldsExt for T {}
 }
 
-#[cfg(feature = "as_ref")]
+#[cfg(any(feature = "as_ref", feature = "try_unwrap", feature = "unwrap"))]
 mod generics_search {
     use syn::visit::Visit;
 
@@ -2329,7 +2695,7 @@ mod generics_search {
 
     impl<'s, 'ast> Visit<'ast> for Visitor<'s> {
         fn visit_type_path(&mut self, tp: &'ast syn::TypePath) {
-            self.found |= tp.path.get_ident().map_or(false, |ident| {
+            self.found |= tp.path.get_ident().is_some_and(|ident| {
                 self.search.types.contains(ident) || self.search.consts.contains(ident)
             });
 
@@ -2346,9 +2712,244 @@ mod generics_search {
             self.found |= ep
                 .path
                 .get_ident()
-                .map_or(false, |ident| self.search.consts.contains(ident));
+                .is_some_and(|ident| self.search.consts.contains(ident));
 
             syn::visit::visit_expr_path(self, ep)
         }
     }
 }
+
+#[cfg(any(feature = "try_unwrap", feature = "unwrap"))]
+mod unwrapped_fields {
+    use proc_macro2::TokenStream;
+    use quote::{format_ident, quote, ToTokens as _};
+    use syn::{punctuated::Punctuated, Fields};
+
+    use super::{GenericsSearch, RefType};
+
+    /// Fields of an enum variant, returned by the methods generated by `Unwrap` and `TryUnwrap`
+    /// derives.
+    ///
+    /// Fields are returned as a tuple, unless they're named and a view struct is requested for
+    /// them, preserving their names.
+    pub(crate) struct UnwrappedFields<'a> {
+        /// Variant containing the fields.
+        variant: &'a syn::Variant,
+
+        /// Name of the generated view struct, if the fields should be returned in it.
+        view: Option<syn::Ident>,
+
+        /// [`syn::Generics`] of the generated view struct, containing only the generic
+        /// parameters used by the fields.
+        generics: syn::Generics,
+
+        /// Identifiers to bind the fields to in patterns.
+        bindings: Vec<syn::Ident>,
+    }
+
+    impl<'a> UnwrappedFields<'a> {
+        /// Creates new [`UnwrappedFields`] of the provided `variant`, returned in a view struct if
+        /// `named` is `true` and the variant has named fields.
+        pub(crate) fn new(
+            input: &syn::DeriveInput,
+            variant: &'a syn::Variant,
+            named: bool,
+        ) -> Self {
+            let view =
+                (named && matches!(variant.fields, Fields::Named(_))).then(|| {
+                    format_ident!(
+                        "{}{}Fields",
+                        input.ident,
+                        variant.ident,
+                        span = variant.ident.span(),
+                    )
+                });
+            let generics = if view.is_some() {
+                used_generics(&input.generics, &variant.fields)
+            } else {
+                syn::Generics::default()
+            };
+            let bindings = (0..variant.fields.len())
+                .map(|n| format_ident!("field_{n}"))
+                .collect();
+            Self {
+                variant,
+                view,
+                generics,
+                bindings,
+            }
+        }
+
+        /// Returns the pattern binding the fields of the variant.
+        pub(crate) fn pattern(&self) -> TokenStream {
+            let bindings = &self.bindings;
+            match &self.variant.fields {
+                Fields::Named(fields) => {
+                    let idents = fields.named.iter().map(|f| &f.ident);
+                    quote! { { #( #idents: #bindings ),* } }
+                }
+                Fields::Unnamed(_) => quote! { ( #( #bindings ),* ) },
+                Fields::Unit => quote! {},
+            }
+        }
+
+        /// Returns the expression constructing the returned value out of the bound fields.
+        pub(crate) fn value(&self, ref_type: RefType) -> TokenStream {
+            let bindings = &self.bindings;
+            match &self.view {
+                Some(view) => {
+                    let view = view_name(view, ref_type);
+                    let idents = self.variant.fields.iter().map(|f| &f.ident);
+                    quote! { #view { #( #idents: #bindings ),* } }
+                }
+                None => quote! { ( #( #bindings ),* ) },
+            }
+        }
+
+        /// Returns the type of the returned value.
+        pub(crate) fn ty(&self, ref_type: RefType) -> TokenStream {
+            let Some(view) = &self.view else {
+                let reference = ref_type.reference();
+                let tys = self.variant.fields.iter().map(|f| &f.ty);
+                return quote! { ( #( #reference #tys ),* ) };
+            };
+
+            let view = view_name(view, ref_type);
+            let lifetime = ref_type.is_ref().then(|| quote! { '_ }).into_iter();
+            let params = self.generics.params.iter().map(|param| match param {
+                syn::GenericParam::Type(ty) => ty.ident.to_token_stream(),
+                syn::GenericParam::Lifetime(lf) => lf.lifetime.to_token_stream(),
+                syn::GenericParam::Const(c) => c.ident.to_token_stream(),
+            });
+            quote! { #view < #( #lifetime, )* #( #params ),* > }
+        }
+
+        /// Returns the definitions of the view structs (owned, ref and ref_mut ones), if the
+        /// fields are returned in them.
+        pub(crate) fn view_structs(&self, vis: &syn::Visibility) -> TokenStream {
+            let Some(view) = &self.view else {
+                return quote! {};
+            };
+            let variant = &self.variant.ident;
+
+            [RefType::No, RefType::Ref, RefType::Mut]
+                .into_iter()
+                .map(|ref_type| {
+                    let name = view_name(view, ref_type);
+                    let mut generics = self.generics.clone();
+                    if ref_type.is_ref() {
+                        let lifetime = ref_type.lifetime();
+                        generics.params.insert(0, syn::parse_quote! { #lifetime });
+                    }
+                    let params = &generics.params;
+                    let where_clause = &generics.where_clause;
+                    let reference = ref_type.reference_with_lifetime();
+                    let fields = self.variant.fields.iter().map(|f| {
+                        let docs = f.attrs.iter().filter(|a| a.path().is_ident("doc"));
+                        let ident = &f.ident;
+                        let ty = &f.ty;
+                        quote! { #( #docs )* #vis #ident: #reference #ty }
+                    });
+                    let doc = match ref_type {
+                        RefType::No => format!("Fields of a `{variant}` variant."),
+                        RefType::Ref => {
+                            format!("References to the fields of a `{variant}` variant.")
+                        }
+                        RefType::Mut => format!(
+                            "Mutable references to the fields of a `{variant}` variant.",
+                        ),
+                    };
+                    quote! {
+                        #[doc = #doc]
+                        #vis struct #name <#params> #where_clause {
+                            #( #fields ),*
+                        }
+                    }
+                })
+                .collect()
+        }
+    }
+
+    /// Returns the name of the view struct for the provided [`RefType`].
+    fn view_name(view: &syn::Ident, ref_type: RefType) -> syn::Ident {
+        match ref_type {
+            RefType::No => view.clone(),
+            RefType::Ref => format_ident!("{view}Ref"),
+            RefType::Mut => format_ident!("{view}Mut"),
+        }
+    }
+
+    /// Returns the [`syn::Generics`] containing only the generic parameters used by the provided
+    /// [`Fields`], along with the `where` predicates not referring to the other ones.
+    fn used_generics(generics: &syn::Generics, fields: &Fields) -> syn::Generics {
+        let is_used =
+            |search: GenericsSearch<'_>| fields.iter().any(|f| search.any_in(&f.ty));
+        let (used, unused): (Vec<_>, Vec<_>) =
+            generics.params.iter().cloned().partition(|param| {
+                let mut search = GenericsSearch {
+                    types: Default::default(),
+                    lifetimes: Default::default(),
+                    consts: Default::default(),
+                };
+                match param {
+                    syn::GenericParam::Type(ty) => search.types.insert(&ty.ident),
+                    syn::GenericParam::Lifetime(lf) => {
+                        search.lifetimes.insert(&lf.lifetime.ident)
+                    }
+                    syn::GenericParam::Const(c) => search.consts.insert(&c.ident),
+                };
+                is_used(search)
+            });
+
+        let unused_search = GenericsSearch {
+            types: unused
+                .iter()
+                .filter_map(|p| match p {
+                    syn::GenericParam::Type(ty) => Some(&ty.ident),
+                    _ => None,
+                })
+                .collect(),
+            lifetimes: unused
+                .iter()
+                .filter_map(|p| match p {
+                    syn::GenericParam::Lifetime(lf) => Some(&lf.lifetime.ident),
+                    _ => None,
+                })
+                .collect(),
+            consts: unused
+                .iter()
+                .filter_map(|p| match p {
+                    syn::GenericParam::Const(c) => Some(&c.ident),
+                    _ => None,
+                })
+                .collect(),
+        };
+        let where_clause = generics.where_clause.as_ref().map(|clause| {
+            let predicates = clause
+                .predicates
+                .iter()
+                .filter(|pred| match pred {
+                    syn::WherePredicate::Type(pred) => {
+                        !unused_search.any_in(&pred.bounded_ty)
+                    }
+                    syn::WherePredicate::Lifetime(pred) => {
+                        !unused_search.lifetimes.contains(&pred.lifetime.ident)
+                    }
+                    _ => true,
+                })
+                .cloned()
+                .collect::<Punctuated<_, _>>();
+            syn::WhereClause {
+                where_token: clause.where_token,
+                predicates,
+            }
+        });
+
+        syn::Generics {
+            lt_token: generics.lt_token,
+            params: used.into_iter().collect(),
+            gt_token: generics.gt_token,
+            where_clause,
+        }
+    }
+}
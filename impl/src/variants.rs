@@ -0,0 +1,87 @@
+//! Implementation of a [`Variants`] derive macro.
+
+use crate::{
+    from_str::{self, EnumAttribute},
+    utils::{attr::ParseMultiple as _, Spanning},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned as _, DeriveInput, Fields, Result};
+
+/// Expands a [`Variants`] derive macro.
+pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            format!("`{trait_name}` can only be derived for enums"),
+        ));
+    };
+
+    let attr = EnumAttribute::parse_attrs(&input.attrs, &format_ident!("from_str"))?
+        .map(Spanning::into_inner)
+        .unwrap_or_default();
+
+    let mut variants = vec![];
+    let mut names = vec![];
+    for variant in &data.variants {
+        if !variant.fields.is_empty() {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!("`{trait_name}` can be derived only for enums with fieldless variants"),
+            ));
+        }
+        let ident = &variant.ident;
+        variants.push(match variant.fields {
+            Fields::Named(_) => quote! { Self::#ident {} },
+            Fields::Unnamed(_) => quote! { Self::#ident() },
+            Fields::Unit => quote! { Self::#ident },
+        });
+        names.push(match from_str::explicit_variant_name(variant)? {
+            Some(name) => name,
+            None => display_name(variant).unwrap_or_else(|| attr.default_name(ident)),
+        });
+    }
+    let count = variants.len();
+
+    let enum_name = &input.ident;
+    let (imp_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #imp_generics #enum_name #type_generics #where_clause {
+            /// All the variants of this enum, in their declaration order.
+            pub const VARIANTS: &'static [Self] = &[#( #variants ),*];
+
+            /// Number of the variants of this enum.
+            pub const COUNT: usize = #count;
+
+            /// Names of all the variants of this enum, in their declaration order.
+            pub const VARIANT_NAMES: &'static [&'static str] = &[#( #names ),*];
+
+            /// Returns the name of the variant of this value.
+            #[inline]
+            #[must_use]
+            pub const fn variant_name(&self) -> &'static str {
+                match *self {
+                    #( #variants => #names, )*
+                }
+            }
+        }
+    })
+}
+
+/// Returns the name of the provided fieldless `variant`, specified by its
+/// `#[display("...")]` attribute without placeholders, if any, with its `{{` and `}}`
+/// escapes resolved.
+///
+/// Other `#[display(...)]` attributes are ignored here, being validated by the `Display`
+/// derive itself.
+fn display_name(variant: &syn::Variant) -> Option<String> {
+    variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("display"))
+        .find_map(|attr| {
+            from_str::literal_name(&attr.parse_args::<syn::LitStr>().ok()?)
+        })
+}
@@ -30,8 +30,8 @@ impl fmt::Display for WrongVariantError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for WrongVariantError {}
+#[cfg(any(feature = "std", error_in_core))]
+impl crate::error::Error for WrongVariantError {}
 
 /// Possible errors returned by the derived implementations of binary
 /// arithmetic or logic operations.
@@ -46,19 +46,17 @@ pub enum BinaryError {
 
 impl fmt::Display for BinaryError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Mismatch(e) => write!(f, "{e}"),
-            Self::Unit(e) => write!(f, "{e}"),
-        }
+        // The wrapped error is reported via `source()` instead.
+        f.write_str("Binary operation failed")
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for BinaryError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+#[cfg(any(feature = "std", error_in_core))]
+impl crate::error::Error for BinaryError {
+    fn source(&self) -> Option<&(dyn crate::error::Error + 'static)> {
         match self {
-            Self::Mismatch(e) => e.source(),
-            Self::Unit(e) => e.source(),
+            Self::Mismatch(e) => Some(e),
+            Self::Unit(e) => Some(e),
         }
     }
 }
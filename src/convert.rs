@@ -42,9 +42,9 @@ mod try_from {
         }
     }
 
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", error_in_core))]
     // `T` should only be an integer type and therefore be debug
-    impl<T: fmt::Debug> std::error::Error for TryFromReprError<T> {}
+    impl<T: fmt::Debug> crate::error::Error for TryFromReprError<T> {}
 }
 
 #[cfg(feature = "try_into")]
@@ -61,7 +61,8 @@ mod try_into {
         ///
         /// [`TryInto`]: macro@crate::TryInto
         pub input: T,
-        variant_names: &'static str,
+        variant_name: &'static str,
+        expected_variants: &'static [&'static str],
         output_type: &'static str,
     }
 
@@ -71,27 +72,49 @@ mod try_into {
         #[inline]
         pub const fn new(
             input: T,
-            variant_names: &'static str,
+            variant_name: &'static str,
+            expected_variants: &'static [&'static str],
             output_type: &'static str,
         ) -> Self {
             Self {
                 input,
-                variant_names,
+                variant_name,
+                expected_variants,
                 output_type,
             }
         }
+
+        /// Returns the name of the variant of the [`input`] value.
+        ///
+        /// [`input`]: TryIntoError::input
+        #[must_use]
+        #[inline]
+        pub const fn variant_name(&self) -> &'static str {
+            self.variant_name
+        }
+
+        /// Returns the names of the variants which can be converted into the
+        /// output type.
+        #[must_use]
+        #[inline]
+        pub const fn expected_variants(&self) -> &'static [&'static str] {
+            self.expected_variants
+        }
     }
 
     impl<T> fmt::Display for TryIntoError<T> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(
-                f,
-                "Only {} can be converted to {}",
-                self.variant_names, self.output_type,
-            )
+            write!(f, "Only ")?;
+            for (n, name) in self.expected_variants.iter().enumerate() {
+                if n > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{name}")?;
+            }
+            write!(f, " can be converted to {}", self.output_type)
         }
     }
 
-    #[cfg(feature = "std")]
-    impl<T: fmt::Debug> std::error::Error for TryIntoError<T> {}
+    #[cfg(any(feature = "std", error_in_core))]
+    impl<T: fmt::Debug> crate::error::Error for TryIntoError<T> {}
 }
@@ -24,8 +24,13 @@
 //! [`AddAssign`-like]: macro@crate::AddAssign
 //! [`MulAssign`-like]: macro@crate::MulAssign
 //!
+//! [`Builder`]: macro@crate::Builder
 //! [`Constructor`]: macro@crate::Constructor
+//! [`Getters`]: macro@crate::Getters
+//! [`Setters`]: macro@crate::Setters
 //! [`IsVariant`]: macro@crate::IsVariant
+//! [`Kind`]: macro@crate::Kind
+//! [`Variants`]: macro@crate::Variants
 //! [`Unwrap`]: macro@crate::Unwrap
 //! [`TryUnwrap`]: macro@crate::TryUnwrap
 
@@ -47,6 +52,9 @@
 #![forbid(non_ascii_idents, unsafe_code)]
 #![warn(clippy::nonstandard_macro_braces)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 // For macro expansion internals only.
 // Ensures better hygiene in case a local crate `core` is present in workspace of the user code,
 // or some other crate is renamed as `core`.
@@ -64,6 +72,9 @@ pub mod __private {
 
     #[cfg(feature = "error")]
     pub use crate::vendor::thiserror::aserror::AsDynError;
+
+    #[cfg(feature = "from_str")]
+    pub use crate::r#str::eq_ignore_case;
 }
 
 /// Module containing macro definitions only, without corresponding traits.
@@ -79,6 +90,16 @@ pub mod derive {
 
 // The modules containing error types and other helpers.
 
+// `Error` trait implemented by the error types below, whenever it's available.
+#[cfg(any(feature = "std", error_in_core))]
+#[allow(unused_imports)] // unused if no feature with error types is enabled
+mod error {
+    #[cfg(error_in_core)]
+    pub(crate) use core::error::Error;
+    #[cfg(not(error_in_core))]
+    pub(crate) use std::error::Error;
+}
+
 #[cfg(feature = "add")]
 mod add;
 #[cfg(feature = "add")]
@@ -103,6 +124,9 @@ mod r#str;
 #[cfg(feature = "from_str")]
 #[doc(inline)]
 pub use crate::r#str::FromStrError;
+#[cfg(all(feature = "from_str", feature = "alloc"))]
+#[doc(inline)]
+pub use crate::r#str::FromStrInputError;
 
 #[cfg(any(feature = "try_into", feature = "try_from"))]
 mod convert;
@@ -239,6 +263,9 @@ mod all_traits_and_derives {
     #[cfg(feature = "as_ref")]
     pub use derive_more_impl::{AsMut, AsRef};
 
+    #[cfg(feature = "builder")]
+    pub use derive_more_impl::Builder;
+
     #[cfg(feature = "constructor")]
     pub use derive_more_impl::Constructor;
 
@@ -265,6 +292,9 @@ mod all_traits_and_derives {
     #[cfg(feature = "from_str")]
     pub use derive_more_impl::FromStr;
 
+    #[cfg(feature = "getters")]
+    pub use derive_more_impl::Getters;
+
     #[cfg(feature = "index")]
     pub use derive_more_impl::Index;
 
@@ -280,6 +310,9 @@ mod all_traits_and_derives {
     #[cfg(feature = "is_variant")]
     pub use derive_more_impl::IsVariant;
 
+    #[cfg(feature = "kind")]
+    pub use derive_more_impl::Kind;
+
     #[cfg(feature = "mul")]
     pub use derive_more_impl::{Div, Mul, Rem, Shl, Shr};
 
@@ -289,6 +322,9 @@ mod all_traits_and_derives {
     #[cfg(feature = "not")]
     pub use derive_more_impl::{Neg, Not};
 
+    #[cfg(feature = "setters")]
+    pub use derive_more_impl::Setters;
+
     #[cfg(feature = "sum")]
     pub use derive_more_impl::{Product, Sum};
 
@@ -303,6 +339,9 @@ mod all_traits_and_derives {
 
     #[cfg(feature = "unwrap")]
     pub use derive_more_impl::Unwrap;
+
+    #[cfg(feature = "variants")]
+    pub use derive_more_impl::Variants;
 }
 
 // Now re-export our own derives and the std traits by their exact name to make rust-analyzer
@@ -324,6 +363,10 @@ pub use all_traits_and_derives::{
 #[doc(hidden)]
 pub use all_traits_and_derives::{AsMut, AsRef};
 
+#[cfg(feature = "builder")]
+#[doc(hidden)]
+pub use all_traits_and_derives::Builder;
+
 #[cfg(feature = "constructor")]
 #[doc(hidden)]
 pub use all_traits_and_derives::Constructor;
@@ -358,6 +401,10 @@ pub use all_traits_and_derives::From;
 #[doc(hidden)]
 pub use all_traits_and_derives::FromStr;
 
+#[cfg(feature = "getters")]
+#[doc(hidden)]
+pub use all_traits_and_derives::Getters;
+
 #[cfg(feature = "index")]
 #[doc(hidden)]
 pub use all_traits_and_derives::Index;
@@ -378,6 +425,10 @@ pub use all_traits_and_derives::IntoIterator;
 #[doc(hidden)]
 pub use all_traits_and_derives::IsVariant;
 
+#[cfg(feature = "kind")]
+#[doc(hidden)]
+pub use all_traits_and_derives::Kind;
+
 #[cfg(feature = "mul")]
 #[doc(hidden)]
 pub use all_traits_and_derives::{Div, Mul, Rem, Shl, Shr};
@@ -392,6 +443,10 @@ pub use all_traits_and_derives::{
 #[doc(hidden)]
 pub use all_traits_and_derives::{Neg, Not};
 
+#[cfg(feature = "setters")]
+#[doc(hidden)]
+pub use all_traits_and_derives::Setters;
+
 #[cfg(feature = "sum")]
 #[doc(hidden)]
 pub use all_traits_and_derives::{Product, Sum};
@@ -412,6 +467,10 @@ pub use all_traits_and_derives::TryUnwrap;
 #[doc(hidden)]
 pub use all_traits_and_derives::Unwrap;
 
+#[cfg(feature = "variants")]
+#[doc(hidden)]
+pub use all_traits_and_derives::Variants;
+
 // Re-export the derive macros again to show docs for our derives (but not for traits). This is
 // done using a glob import to not hit E0252.
 #[allow(unused_imports)]
@@ -423,6 +482,7 @@ pub use derive_more_impl::*;
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "builder",
     feature = "constructor",
     feature = "debug",
     feature = "deref",
@@ -431,19 +491,23 @@ pub use derive_more_impl::*;
     feature = "error",
     feature = "from",
     feature = "from_str",
+    feature = "getters",
     feature = "index",
     feature = "index_mut",
     feature = "into",
     feature = "into_iterator",
     feature = "is_variant",
+    feature = "kind",
     feature = "mul",
     feature = "mul_assign",
     feature = "not",
+    feature = "setters",
     feature = "sum",
     feature = "try_from",
     feature = "try_into",
     feature = "try_unwrap",
     feature = "unwrap",
+    feature = "variants",
 )))]
 compile_error!(
     "at least one derive feature must be enabled (or the \"full\" feature enabling all the derives)"
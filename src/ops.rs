@@ -24,5 +24,5 @@ impl fmt::Display for UnitError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for UnitError {}
+#[cfg(any(feature = "std", error_in_core))]
+impl crate::error::Error for UnitError {}
@@ -1,25 +1,130 @@
 use core::fmt;
 
-/// Error of parsing an enum value its string representation.
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// Error of parsing a value from its string representation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct FromStrError {
     type_name: &'static str,
+    expected: &'static [&'static str],
 }
 
 impl FromStrError {
     #[doc(hidden)]
     #[must_use]
     #[inline]
-    pub const fn new(type_name: &'static str) -> Self {
-        Self { type_name }
+    pub const fn new(
+        type_name: &'static str,
+        expected: &'static [&'static str],
+    ) -> Self {
+        Self {
+            type_name,
+            expected,
+        }
+    }
+
+    /// Returns the name of the type failed to be parsed.
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the accepted string representations of the type failed to be parsed.
+    ///
+    /// These are the names (and aliases) of enum variants and the formats specified via
+    /// `#[from_str("...")]` attributes. Empty if the accepted representations cannot be
+    /// enumerated (like the ones parsed by a `#[from_str(try_each)]` variant).
+    #[must_use]
+    pub const fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+
+    /// Attaches the provided `input` (failed to be parsed) to this error.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn with_input(self, input: &str) -> FromStrInputError {
+        FromStrInputError {
+            error: self,
+            input: input.into(),
+        }
+    }
+
+    /// Writes the message of this error, mentioning the `input`, if any.
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, input: Option<&str>) -> fmt::Result {
+        write!(f, "Invalid `{}` string representation", self.type_name)?;
+        if let Some(input) = input {
+            write!(f, " `{input}`")?;
+        }
+        if let Some((first, rest)) = self.expected.split_first() {
+            write!(f, ", expected one of: {first}")?;
+            for name in rest {
+                write!(f, ", {name}")?;
+            }
+        }
+        Ok(())
     }
 }
 
 impl fmt::Display for FromStrError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Invalid `{}` string representation", self.type_name)
+        self.fmt_with(f, None)
+    }
+}
+
+#[cfg(any(feature = "std", error_in_core))]
+impl crate::error::Error for FromStrError {}
+
+/// [`FromStrError`] along with the input string failed to be parsed.
+///
+/// Created by the [`FromStrError::with_input()`] method.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FromStrInputError {
+    error: FromStrError,
+    input: alloc::string::String,
+}
+
+#[cfg(feature = "alloc")]
+impl FromStrInputError {
+    /// Returns the [`FromStrError`] of this error.
+    #[must_use]
+    pub const fn error(&self) -> FromStrError {
+        self.error
+    }
+
+    /// Returns the input string failed to be parsed.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<FromStrInputError> for FromStrError {
+    fn from(err: FromStrInputError) -> Self {
+        err.error
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for FromStrInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt_with(f, Some(&self.input))
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for FromStrError {}
+#[cfg(all(feature = "alloc", any(feature = "std", error_in_core)))]
+impl crate::error::Error for FromStrInputError {}
+
+/// Checks whether the provided `src` string equals to the `lowercase` one, regardless of
+/// the `src` case.
+///
+/// The `lowercase` string is expected to be lowercased per [`char::to_lowercase()`].
+///
+/// Doesn't allocate, unlike comparing with a [`str::to_lowercase()`] result.
+#[doc(hidden)]
+#[must_use]
+#[inline]
+pub fn eq_ignore_case(src: &str, lowercase: &str) -> bool {
+    src.chars()
+        .flat_map(char::to_lowercase)
+        .eq(lowercase.chars())
+}
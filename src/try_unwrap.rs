@@ -44,5 +44,5 @@ impl<T> core::fmt::Display for TryUnwrapError<T> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<T: core::fmt::Debug> std::error::Error for TryUnwrapError<T> {}
+#[cfg(any(feature = "std", error_in_core))]
+impl<T: core::fmt::Debug> crate::error::Error for TryUnwrapError<T> {}
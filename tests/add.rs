@@ -22,3 +22,412 @@ enum MixedInts {
     UnsignedTwo(u32),
     Unit,
 }
+
+mod custom_rhs {
+    use core::time::Duration;
+
+    use derive_more::{Add, BitOr, Sub};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Delta {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Add, Debug, PartialEq, Sub)]
+    #[add(rhs = Delta)]
+    #[sub(rhs = Delta)]
+    struct Offset {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn fieldwise() {
+        let delta = Delta { x: 3, y: 4 };
+
+        assert_eq!(Offset { x: 1, y: 2 } + delta, Offset { x: 4, y: 6 });
+        assert_eq!(Offset { x: 1, y: 2 } - delta, Offset { x: -2, y: -2 });
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Ticks(u64);
+
+    #[derive(Add, Debug, PartialEq)]
+    #[add(rhs = Ticks)]
+    struct Instant(u64);
+
+    #[test]
+    fn fieldwise_tuple() {
+        assert_eq!(Instant(1) + Ticks(2), Instant(3));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct GenericDelta<T>(T, T);
+
+    #[derive(Add, Debug, PartialEq)]
+    #[add(rhs = GenericDelta<T>)]
+    struct GenericOffset<T>(T, T);
+
+    #[test]
+    fn fieldwise_generic() {
+        assert_eq!(
+            GenericOffset(1, 2) + GenericDelta(3, 4),
+            GenericOffset(4, 6),
+        );
+    }
+
+    #[derive(Add, Debug, PartialEq, Sub)]
+    #[add(rhs = Duration)]
+    #[sub(rhs = Duration)]
+    struct Deadline {
+        #[add(rhs)]
+        #[sub(rhs)]
+        at: Duration,
+        label: &'static str,
+    }
+
+    #[test]
+    fn designated_field() {
+        let deadline = Deadline {
+            at: Duration::from_secs(5),
+            label: "job",
+        };
+
+        assert_eq!(
+            deadline + Duration::from_secs(2),
+            Deadline {
+                at: Duration::from_secs(7),
+                label: "job",
+            },
+        );
+        assert_eq!(
+            Deadline {
+                at: Duration::from_secs(5),
+                label: "job",
+            } - Duration::from_secs(2),
+            Deadline {
+                at: Duration::from_secs(3),
+                label: "job",
+            },
+        );
+    }
+
+    #[derive(BitOr, Debug, PartialEq)]
+    struct Flags(&'static str, #[bitor(rhs)] u8);
+
+    #[test]
+    fn designated_field_inferred_rhs() {
+        assert_eq!(Flags("rw", 0b01) | 0b10, Flags("rw", 0b11));
+    }
+
+    #[derive(Add, Debug, PartialEq)]
+    struct Tagged<T, Tag> {
+        #[add(rhs)]
+        value: T,
+        tag: Tag,
+    }
+
+    #[test]
+    fn designated_field_generic() {
+        assert_eq!(
+            Tagged {
+                value: 1.5,
+                tag: "x"
+            } + 2.0,
+            Tagged {
+                value: 3.5,
+                tag: "x"
+            },
+        );
+    }
+}
+
+mod by_ref {
+    use derive_more::{Add, BitAnd, Sub};
+
+    /// Non-`Copy` type, supporting addition of both values and references.
+    #[derive(Debug, PartialEq)]
+    struct Heavy(i32);
+
+    impl core::ops::Add for Heavy {
+        type Output = Heavy;
+
+        fn add(self, rhs: Heavy) -> Heavy {
+            Heavy(self.0 + rhs.0)
+        }
+    }
+
+    impl<'a> core::ops::Add<&'a Heavy> for &'a Heavy {
+        type Output = Heavy;
+
+        fn add(self, rhs: &'a Heavy) -> Heavy {
+            Heavy(self.0 + rhs.0)
+        }
+    }
+
+    #[derive(Add, Debug, PartialEq)]
+    #[add(ref)]
+    struct Pair {
+        left: Heavy,
+        right: Heavy,
+    }
+
+    #[test]
+    fn named() {
+        let a = Pair {
+            left: Heavy(1),
+            right: Heavy(2),
+        };
+        let b = Pair {
+            left: Heavy(3),
+            right: Heavy(4),
+        };
+        let expected = Pair {
+            left: Heavy(4),
+            right: Heavy(6),
+        };
+
+        assert_eq!(&a + &b, expected);
+        assert_eq!(&a + b, expected);
+
+        let b = Pair {
+            left: Heavy(3),
+            right: Heavy(4),
+        };
+        assert_eq!(a + &b, expected);
+    }
+
+    #[derive(Add, Debug, PartialEq, Sub)]
+    #[add(ref)]
+    #[sub(ref)]
+    struct Point(i32, i32);
+
+    #[test]
+    fn unnamed() {
+        let a = Point(1, 2);
+        let b = Point(3, 5);
+
+        assert_eq!(&a + &b, Point(4, 7));
+        assert_eq!(&b - &a, Point(2, 3));
+        assert_eq!(a + b, Point(4, 7));
+    }
+
+    #[derive(Add, Debug, PartialEq)]
+    #[add(ref)]
+    struct Wrapper<T>(T);
+
+    #[test]
+    fn generic() {
+        let a = Wrapper(Heavy(1));
+        let b = Wrapper(Heavy(2));
+
+        assert_eq!(&a + &b, Wrapper(Heavy(3)));
+        assert_eq!(&Wrapper(1) + Wrapper(2), Wrapper(3));
+    }
+
+    #[derive(BitAnd, Debug, PartialEq)]
+    #[bitand(ref)]
+    enum Mask {
+        Byte(u8),
+        Pair { low: u8, high: u8 },
+        Empty,
+    }
+
+    #[test]
+    fn enums() {
+        assert_eq!(
+            (&Mask::Byte(0b0110) & &Mask::Byte(0b0011)).unwrap(),
+            Mask::Byte(0b0010)
+        );
+        assert_eq!(
+            (&Mask::Pair { low: 1, high: 3 } & Mask::Pair { low: 3, high: 2 }).unwrap(),
+            Mask::Pair { low: 1, high: 2 },
+        );
+        assert!((&Mask::Byte(1) & &Mask::Empty).is_err());
+    }
+}
+
+mod skip {
+    use core::marker::PhantomData;
+
+    use derive_more::{Add, BitAnd, Sub};
+
+    #[derive(Debug, PartialEq)]
+    struct Meters;
+
+    #[derive(Add, Debug, PartialEq, Sub)]
+    struct Length<U> {
+        value: i32,
+        #[add(skip)]
+        #[sub(skip)]
+        unit: PhantomData<U>,
+    }
+
+    #[test]
+    fn phantom() {
+        let length = |value| Length::<Meters> {
+            value,
+            unit: PhantomData,
+        };
+
+        assert_eq!(length(1) + length(2), length(3));
+        assert_eq!(length(1) - length(2), length(-1));
+    }
+
+    #[derive(Add, Debug, PartialEq)]
+    struct Sample {
+        value: i32,
+        #[add(skip, keep = "lhs")]
+        name: &'static str,
+        #[add(skip, default)]
+        id: u32,
+    }
+
+    #[test]
+    fn keep_and_default() {
+        let lhs = Sample {
+            value: 1,
+            name: "left",
+            id: 1,
+        };
+        let rhs = Sample {
+            value: 2,
+            name: "right",
+            id: 2,
+        };
+
+        assert_eq!(
+            lhs + rhs,
+            Sample {
+                value: 3,
+                name: "left",
+                id: 0,
+            },
+        );
+    }
+
+    #[derive(BitAnd, Debug, PartialEq)]
+    struct Tagged(u8, #[bitand(skip)] &'static str);
+
+    #[test]
+    fn tuple() {
+        assert_eq!(
+            Tagged(0b0110, "left") & Tagged(0b0011, "right"),
+            Tagged(0b0010, "left"),
+        );
+    }
+
+    #[derive(Add, Debug, PartialEq)]
+    enum Reading {
+        Temperature(i32, #[add(skip)] &'static str),
+        Pressure {
+            value: u32,
+            #[add(skip, default)]
+            sensor: u8,
+        },
+    }
+
+    #[test]
+    fn enum_variants() {
+        assert_eq!(
+            (Reading::Temperature(20, "left") + Reading::Temperature(1, "right"))
+                .unwrap(),
+            Reading::Temperature(21, "left"),
+        );
+        assert_eq!(
+            (Reading::Pressure {
+                value: 1,
+                sensor: 1,
+            } + Reading::Pressure {
+                value: 2,
+                sensor: 2,
+            })
+            .unwrap(),
+            Reading::Pressure {
+                value: 3,
+                sensor: 0,
+            },
+        );
+    }
+
+    #[derive(Add, Debug, PartialEq)]
+    #[add(ref)]
+    struct Labeled {
+        value: i32,
+        #[add(skip)]
+        label: &'static str,
+        #[add(skip, default)]
+        hits: u32,
+    }
+
+    #[test]
+    fn by_ref() {
+        let lhs = Labeled {
+            value: 1,
+            label: "left",
+            hits: 1,
+        };
+        let rhs = Labeled {
+            value: 2,
+            label: "right",
+            hits: 2,
+        };
+        let expected = Labeled {
+            value: 3,
+            label: "left",
+            hits: 0,
+        };
+
+        assert_eq!(&lhs + &rhs, expected);
+        assert_eq!(lhs + rhs, expected);
+    }
+
+    #[derive(Add, Debug, PartialEq)]
+    #[add(rhs = u32)]
+    struct Counter {
+        #[add(rhs)]
+        count: u32,
+        #[add(skip, default)]
+        cached: Option<u32>,
+        label: &'static str,
+    }
+
+    #[test]
+    fn custom_rhs() {
+        let counter = Counter {
+            count: 1,
+            cached: Some(1),
+            label: "counter",
+        };
+
+        assert_eq!(
+            counter + 2,
+            Counter {
+                count: 3,
+                cached: None,
+                label: "counter",
+            },
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+mod binary_error {
+    use std::error::Error as _;
+
+    use super::MixedInts;
+
+    #[test]
+    fn chains_wrapped_error() {
+        let err = (MixedInts::SmallInt(1) + MixedInts::BigInt(2))
+            .err()
+            .unwrap();
+
+        assert_eq!(err.to_string(), "Binary operation failed");
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "Trying to add() mismatched enum variants",
+        );
+    }
+}
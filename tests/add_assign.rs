@@ -11,3 +11,114 @@ struct Point2D {
     x: i32,
     y: i32,
 }
+
+mod custom_rhs {
+    use core::time::Duration;
+
+    use derive_more::{AddAssign, BitXorAssign, SubAssign};
+
+    #[derive(Clone, Copy)]
+    struct Delta {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(AddAssign, Debug, PartialEq, SubAssign)]
+    #[add_assign(rhs = Delta)]
+    #[sub_assign(rhs = Delta)]
+    struct Offset {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn fieldwise() {
+        let mut offset = Offset { x: 1, y: 2 };
+        offset += Delta { x: 3, y: 4 };
+        assert_eq!(offset, Offset { x: 4, y: 6 });
+
+        offset -= Delta { x: 1, y: 1 };
+        assert_eq!(offset, Offset { x: 3, y: 5 });
+    }
+
+    #[derive(AddAssign, Debug, PartialEq)]
+    #[add_assign(rhs = Duration)]
+    struct Timer {
+        #[add_assign(rhs)]
+        elapsed: Duration,
+        laps: u32,
+    }
+
+    #[test]
+    fn designated_field() {
+        let mut timer = Timer {
+            elapsed: Duration::from_secs(5),
+            laps: 1,
+        };
+        timer += Duration::from_secs(3);
+
+        assert_eq!(
+            timer,
+            Timer {
+                elapsed: Duration::from_secs(8),
+                laps: 1,
+            },
+        );
+    }
+
+    #[derive(BitXorAssign, Debug, PartialEq)]
+    struct Masked<T>(#[bitxor_assign(rhs)] T, &'static str);
+
+    #[test]
+    fn designated_field_generic() {
+        let mut masked = Masked(0b0110, "mask");
+        masked ^= 0b0011;
+
+        assert_eq!(masked, Masked(0b0101, "mask"));
+    }
+}
+
+mod skip {
+    use core::marker::PhantomData;
+
+    use derive_more::{AddAssign, SubAssign};
+
+    #[derive(Debug, PartialEq)]
+    struct Meters;
+
+    #[derive(AddAssign, Debug, PartialEq, SubAssign)]
+    struct Length<U> {
+        value: i32,
+        #[add_assign(skip)]
+        #[sub_assign(skip)]
+        unit: PhantomData<U>,
+    }
+
+    #[test]
+    fn phantom() {
+        let length = |value| Length::<Meters> {
+            value,
+            unit: PhantomData,
+        };
+        let mut len = length(1);
+        len += length(2);
+        assert_eq!(len, length(3));
+        len -= length(4);
+        assert_eq!(len, length(-1));
+    }
+
+    #[derive(AddAssign, Debug, PartialEq)]
+    struct Sample(
+        i32,
+        #[add_assign(skip, keep = "lhs")] &'static str,
+        #[add_assign(skip, default)] u32,
+    );
+
+    #[test]
+    fn keep_and_default() {
+        let mut sample = Sample(1, "left", 1);
+        sample += Sample(2, "right", 2);
+
+        assert_eq!(sample, Sample(3, "left", 0));
+    }
+}
@@ -0,0 +1,143 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+use core::marker::PhantomData;
+
+use derive_more::Builder;
+
+#[derive(Builder)]
+struct Empty {}
+
+#[test]
+fn empty() {
+    let Empty {} = Empty::builder().build();
+}
+
+#[derive(Builder, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn required_fields() {
+    assert_eq!(Point::builder().x(1).y(2).build(), Point { x: 1, y: 2 });
+    assert_eq!(Point::builder().y(2).x(1).build(), Point { x: 1, y: 2 });
+}
+
+#[derive(Builder, Debug, PartialEq)]
+struct Config {
+    /// Name of the configuration.
+    #[new(into)]
+    name: String,
+    #[new(value = 8)]
+    threads: usize,
+    #[new(default)]
+    features: Vec<String>,
+    verbose: bool,
+}
+
+#[test]
+fn optional_fields() {
+    assert_eq!(
+        Config::builder().verbose(true).name("app").build(),
+        Config {
+            name: "app".into(),
+            threads: 8,
+            features: vec![],
+            verbose: true,
+        },
+    );
+    assert_eq!(
+        Config::builder()
+            .threads(2)
+            .name(String::from("app"))
+            .features(vec!["a".into()])
+            .threads(4)
+            .verbose(false)
+            .build(),
+        Config {
+            name: "app".into(),
+            threads: 4,
+            features: vec!["a".into()],
+            verbose: false,
+        },
+    );
+}
+
+#[derive(Builder)]
+struct Generic<'a, T: Clone, const N: usize>
+where
+    T: PartialEq,
+{
+    values: [T; N],
+    label: &'a str,
+    #[new(default)]
+    _marker: PhantomData<&'a T>,
+}
+
+#[test]
+fn generic() {
+    let generic = Generic::builder().label("nums").values([1, 2]).build();
+    assert_eq!(generic.values, [1, 2]);
+    assert_eq!(generic.label, "nums");
+}
+
+#[derive(Builder)]
+struct Clashing<__Value> {
+    value: __Value,
+    __value: u8,
+    foo_bar: u16,
+    foo_bar_: u32,
+}
+
+#[test]
+fn clashing_names() {
+    let clashing = Clashing::builder()
+        .foo_bar_(4)
+        .value("a")
+        .foo_bar(3)
+        .__value(2)
+        .build();
+    assert_eq!(clashing.value, "a");
+    assert_eq!(clashing.__value, 2);
+    assert_eq!(clashing.foo_bar, 3);
+    assert_eq!(clashing.foo_bar_, 4);
+}
+
+#[cfg(feature = "constructor")]
+mod with_constructor {
+    use derive_more::{Builder, Constructor};
+
+    #[derive(Builder, Constructor, Debug, PartialEq)]
+    struct Account {
+        id: u64,
+        #[new(value = 100)]
+        limit: u32,
+    }
+
+    #[derive(Builder, Constructor, Debug, PartialEq)]
+    #[new]
+    #[new(name = "with_id", fields(id))]
+    struct Quota {
+        id: u64,
+        #[new(value = 100)]
+        limit: u32,
+    }
+
+    #[test]
+    fn agrees_on_defaults() {
+        assert_eq!(Account::new(1), Account::builder().id(1).build());
+    }
+
+    #[test]
+    fn ignores_named_constructors() {
+        assert_eq!(Quota::with_id(1), Quota::builder().id(1).build());
+        assert_eq!(Quota::new(1), Quota::builder().id(1).build());
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(derive_more::Add)]
+struct Point {
+    #[add(rhs)]
+    x: i32,
+    #[add(rhs)]
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::Add)]
+#[add(rhs = u32, ref)]
+struct Wrapper(u32);
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::Add)]
+#[add(rhs = i32)]
+enum Number {
+    Int(i32),
+    Uint(u32),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::Add)]
+struct Sample {
+    value: i32,
+    #[add(skip, keep = "rhs")]
+    name: &'static str,
+}
+
+fn main() {}
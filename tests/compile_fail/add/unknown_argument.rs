@@ -0,0 +1,8 @@
+#[derive(derive_more::Add)]
+#[add(lhs = i32)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::AddAssign)]
+#[add_assign(ref)]
+struct Wrapper(u32);
+
+fn main() {}
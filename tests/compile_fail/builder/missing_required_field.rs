@@ -0,0 +1,9 @@
+#[derive(derive_more::Builder)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let _ = Point::builder().x(1).build();
+}
@@ -0,0 +1,9 @@
+#[derive(derive_more::Constructor)]
+#[new(name = "with_y", fields(x, y))]
+struct Point {
+    x: i32,
+    #[new(default)]
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::Constructor)]
+#[new(name = "create")]
+enum Shape {
+    Circle(f64),
+    Square(f64),
+}
+
+fn main() {}
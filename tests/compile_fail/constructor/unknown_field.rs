@@ -0,0 +1,8 @@
+#[derive(derive_more::Constructor)]
+#[new(name = "with_x", fields(x, z))]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Constructor)]
+struct Foo {
+    #[new(skip)]
+    bar: i32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::FromStr)]
+#[from_str(case = "insensitive")]
+enum Enum {
+    Baz,
+    BaZ,
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::FromStr)]
+#[from_str("{0}{1}")]
+struct Pair(i32, i32);
+
+fn main() {}
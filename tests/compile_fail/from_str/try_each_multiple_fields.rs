@@ -0,0 +1,8 @@
+#[derive(derive_more::FromStr)]
+#[from_str(try_each)]
+enum Enum {
+    Single(i32),
+    Pair(i32, i32),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::FromStr)]
+#[from_str(case = "lenient")]
+enum Enum {
+    Foo,
+    Bar,
+}
+
+fn main() {}
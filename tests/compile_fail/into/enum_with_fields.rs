@@ -0,0 +1,7 @@
+#[derive(derive_more::Into)]
+#[into(repr)]
+enum Foo {
+    Foo(i32),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::IsVariant)]
+enum Foo {
+    Bar(i32),
+    #[is_variant(and)]
+    Baz,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::IsVariant)]
+enum Foo {
+    #[is_variant(rename = "is bar")]
+    Bar(i32),
+    Baz,
+}
+
+fn main() {}
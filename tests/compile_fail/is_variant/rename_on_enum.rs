@@ -0,0 +1,8 @@
+#[derive(derive_more::IsVariant)]
+#[is_variant(rename = "is_foo")]
+enum Foo {
+    Bar(i32),
+    Baz,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::Not)]
+struct Flags {
+    value: u8,
+    #[not(ignore)]
+    name: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::Setters)]
+struct Point {
+    #[setter(copy)]
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
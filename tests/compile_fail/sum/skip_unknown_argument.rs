@@ -0,0 +1,8 @@
+#[derive(derive_more::Sum)]
+struct Sample {
+    value: i32,
+    #[sum(skip, zero)]
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(Debug)]
+struct Error;
+
+#[derive(derive_more::TryFrom)]
+#[try_from(u16, error(Error))]
+struct Port(u16);
+
+fn main() {}
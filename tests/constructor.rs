@@ -1,6 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(dead_code)] // some code is tested for type checking only
 
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
 use derive_more::Constructor;
 
 #[derive(Constructor)]
@@ -30,3 +33,169 @@ struct Point2D {
 }
 
 const POINT_2D: Point2D = Point2D::new(-4, 7);
+
+mod field_attributes {
+    #[cfg(not(feature = "std"))]
+    use alloc::{string::String, vec::Vec};
+    use core::marker::PhantomData;
+
+    use derive_more::Constructor;
+
+    #[derive(Constructor)]
+    struct Marked<T> {
+        id: u32,
+        #[new(default)]
+        _marker: PhantomData<T>,
+    }
+
+    const MARKED: Marked<String> = Marked::new(1);
+
+    #[derive(Constructor)]
+    struct Tagged(u32, #[new(value = -1)] i8);
+
+    const TAGGED: Tagged = Tagged::new(1);
+
+    #[derive(Constructor)]
+    struct Limits {
+        min: i32,
+        #[new(value = 100)]
+        max: i32,
+    }
+
+    const LIMITS: Limits = Limits::new(0);
+
+    #[derive(Constructor)]
+    struct Computed {
+        min: i32,
+        #[new(value = i32::MAX - 1)]
+        max: i32,
+    }
+
+    #[derive(Constructor)]
+    struct Person {
+        #[new(into)]
+        name: String,
+        age: u8,
+        #[new(default)]
+        aliases: Vec<String>,
+        #[new(value = String::from("unknown"))]
+        city: String,
+    }
+
+    #[derive(Constructor)]
+    struct Wrapped<T>(#[new(into)] T, #[new(default)] u8);
+
+    #[test]
+    fn assert() {
+        assert_eq!(MARKED.id, 1);
+        assert_eq!((TAGGED.0, TAGGED.1), (1, -1));
+        assert_eq!((LIMITS.min, LIMITS.max), (0, 100));
+
+        let computed = Computed::new(0);
+        assert_eq!((computed.min, computed.max), (0, i32::MAX - 1));
+
+        let person = Person::new("John", 42);
+        assert_eq!(person.name, "John");
+        assert_eq!(person.age, 42);
+        assert!(person.aliases.is_empty());
+        assert_eq!(person.city, "unknown");
+
+        let wrapped = Wrapped::<i64>::new(1i32);
+        assert_eq!((wrapped.0, wrapped.1), (1, 0));
+    }
+}
+
+mod enums {
+    #[cfg(not(feature = "std"))]
+    use alloc::string::String;
+
+    use derive_more::Constructor;
+
+    #[derive(Constructor, Debug, PartialEq)]
+    enum Shape {
+        Circle(f64),
+        Rect {
+            width: f64,
+            height: f64,
+        },
+        #[new(name = "square", fields(width))]
+        #[new]
+        Square {
+            width: f64,
+            #[new(value = "square")]
+            label: &'static str,
+        },
+        Empty,
+    }
+
+    const CIRCLE: Shape = Shape::new_circle(1.0);
+
+    #[derive(Constructor, Debug, PartialEq)]
+    #[new(vis = "pub(crate)")]
+    enum Message {
+        Text(#[new(into)] String),
+        #[new(vis = "pub")]
+        Ping,
+    }
+
+    #[test]
+    fn assert() {
+        assert_eq!(CIRCLE, Shape::Circle(1.0));
+        assert_eq!(
+            Shape::new_rect(1.0, 2.0),
+            Shape::Rect {
+                width: 1.0,
+                height: 2.0,
+            },
+        );
+        assert_eq!(
+            Shape::square(3.0),
+            Shape::Square {
+                width: 3.0,
+                label: "square",
+            },
+        );
+        assert_eq!(Shape::new_square(3.0), Shape::square(3.0));
+        assert_eq!(Shape::new_empty(), Shape::Empty);
+
+        assert_eq!(Message::new_text("hi"), Message::Text("hi".into()));
+        assert_eq!(Message::new_ping(), Message::Ping);
+    }
+}
+
+mod named_constructors {
+    #[cfg(not(feature = "std"))]
+    use alloc::{string::String, vec::Vec};
+
+    use derive_more::Constructor;
+
+    #[derive(Constructor)]
+    #[new]
+    #[new(name = "origin", fields())]
+    #[new(name = "on_x_axis", vis = "pub(crate)", fields(x))]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Constructor)]
+    #[new(name = "with_parts", vis = "pub(crate)")]
+    #[new(name = "named", fields(0))]
+    struct Path(#[new(into)] String, Vec<String>);
+
+    #[test]
+    fn assert() {
+        let point = Point::new(1, 2);
+        assert_eq!((point.x, point.y), (1, 2));
+        let origin = Point::origin();
+        assert_eq!((origin.x, origin.y), (0, 0));
+        let point = Point::on_x_axis(3);
+        assert_eq!((point.x, point.y), (3, 0));
+
+        let path = Path::with_parts("root", Vec::new());
+        assert_eq!(path.0, "root");
+        let path = Path::named("root");
+        assert_eq!(path.0, "root");
+        assert!(path.1.is_empty());
+    }
+}
@@ -5,9 +5,26 @@
 extern crate alloc;
 
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 
-use derive_more::FromStr;
+use core::fmt::Debug;
+
+use derive_more::{FromStr, FromStrError};
+
+#[track_caller]
+fn assert_err<T>(input: &str, expected: &[&str])
+where
+    T: Debug + FromStr<Err = FromStrError>,
+{
+    let err = input.parse::<T>().unwrap_err();
+    assert_eq!(err.expected(), expected);
+    #[cfg(feature = "alloc")]
+    {
+        let err = err.with_input(input);
+        assert_eq!(err.input(), input);
+        assert_eq!(err.error(), err.clone().into());
+    }
+}
 
 #[derive(FromStr)]
 struct MyInt(i32);
@@ -30,9 +47,23 @@ fn enum_test() {
     assert_eq!("Foo".parse::<EnumNoFields>().unwrap(), EnumNoFields::Foo);
     assert_eq!("FOO".parse::<EnumNoFields>().unwrap(), EnumNoFields::Foo);
     assert_eq!("foo".parse::<EnumNoFields>().unwrap(), EnumNoFields::Foo);
+    assert_err::<EnumNoFields>("other", &["Foo", "Bar", "Baz", "BaZ"]);
+}
+
+#[test]
+fn error_display() {
+    let err = "other".parse::<EnumNoFields>().unwrap_err();
+    assert_eq!(err.type_name(), "EnumNoFields");
+    assert_eq!(
+        err.to_string(),
+        "Invalid `EnumNoFields` string representation, \
+         expected one of: Foo, Bar, Baz, BaZ",
+    );
+    #[cfg(feature = "alloc")]
     assert_eq!(
-        "other".parse::<EnumNoFields>().unwrap_err().to_string(),
-        "Invalid `EnumNoFields` string representation",
+        err.with_input("other").to_string(),
+        "Invalid `EnumNoFields` string representation `other`, \
+         expected one of: Foo, Bar, Baz, BaZ",
     );
 }
 
@@ -40,8 +71,385 @@ fn enum_test() {
 fn enum_test_case_sensitive() {
     assert_eq!("Baz".parse::<EnumNoFields>().unwrap(), EnumNoFields::Baz);
     assert_eq!("BaZ".parse::<EnumNoFields>().unwrap(), EnumNoFields::BaZ);
+    assert_err::<EnumNoFields>("baz", &["Foo", "Bar", "Baz", "BaZ"]);
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str(rename_all = "kebab-case")]
+enum Access {
+    ReadOnly,
+    #[from_str(alias = "rw", alias = "read+write")]
+    ReadWrite,
+    #[from_str(rename = "none")]
+    Denied,
+}
+
+#[test]
+fn enum_rename_all() {
+    assert_eq!("read-only".parse::<Access>().unwrap(), Access::ReadOnly);
+    assert_eq!("Read-Only".parse::<Access>().unwrap(), Access::ReadOnly);
+    assert_eq!("read-write".parse::<Access>().unwrap(), Access::ReadWrite);
+    assert_err::<Access>(
+        "ReadOnly",
+        &["read-only", "read-write", "rw", "read+write", "none"],
+    );
+}
+
+#[test]
+fn enum_rename_and_alias() {
+    assert_eq!("none".parse::<Access>().unwrap(), Access::Denied);
+    assert_eq!("NONE".parse::<Access>().unwrap(), Access::Denied);
+    assert_eq!("rw".parse::<Access>().unwrap(), Access::ReadWrite);
+    assert_eq!("read+write".parse::<Access>().unwrap(), Access::ReadWrite);
+    assert_err::<Access>(
+        "denied",
+        &["read-only", "read-write", "rw", "read+write", "none"],
+    );
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Level {
+    Info,
+    #[from_str(alias = "warn")]
+    Warning,
+    #[from_str(rename = "error")]
+    Error,
+    #[from_str(rename = "ERROR")]
+    Fatal,
+}
+
+#[test]
+fn enum_rename_case_sensitive() {
+    assert_eq!("INFO".parse::<Level>().unwrap(), Level::Info);
+    assert_eq!("info".parse::<Level>().unwrap(), Level::Info);
+    assert_eq!("Warn".parse::<Level>().unwrap(), Level::Warning);
+    assert_eq!("error".parse::<Level>().unwrap(), Level::Error);
+    assert_eq!("ERROR".parse::<Level>().unwrap(), Level::Fatal);
+    assert_err::<Level>("Error", &["INFO", "WARNING", "warn", "error", "ERROR"]);
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str(case = "sensitive")]
+enum Strict {
+    Foo,
+    #[from_str(alias = "BAR")]
+    Bar,
+    BaZ,
+    Baz,
+}
+
+#[test]
+fn enum_case_sensitive_mode() {
+    assert_eq!("Foo".parse::<Strict>().unwrap(), Strict::Foo);
+    assert_eq!("Bar".parse::<Strict>().unwrap(), Strict::Bar);
+    assert_eq!("BAR".parse::<Strict>().unwrap(), Strict::Bar);
+    assert_eq!("BaZ".parse::<Strict>().unwrap(), Strict::BaZ);
+    assert_eq!("Baz".parse::<Strict>().unwrap(), Strict::Baz);
+    for input in ["foo", "FOO", "bar", "baz"] {
+        assert_err::<Strict>(input, &["Foo", "Bar", "BAR", "BaZ", "Baz"]);
+    }
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str(rename_all = "snake_case", case = "insensitive")]
+enum Lenient {
+    Straße,
+    #[from_str(alias = "ALIAS")]
+    Other,
+}
+
+#[test]
+fn enum_case_insensitive_mode() {
+    assert_eq!("straße".parse::<Lenient>().unwrap(), Lenient::Straße);
+    assert_eq!("STRAßE".parse::<Lenient>().unwrap(), Lenient::Straße);
+    assert_eq!("OTHER".parse::<Lenient>().unwrap(), Lenient::Other);
+    assert_eq!("alias".parse::<Lenient>().unwrap(), Lenient::Other);
+    assert_err::<Lenient>("strasse", &["straße", "other", "ALIAS"]);
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str(case = "ascii_insensitive")]
+enum AsciiLenient {
+    Straße,
+    #[from_str(rename = "other")]
+    Other,
+}
+
+#[test]
+fn enum_case_ascii_insensitive_mode() {
+    assert_eq!(
+        "STRAßE".parse::<AsciiLenient>().unwrap(),
+        AsciiLenient::Straße
+    );
+    assert_eq!(
+        "Other".parse::<AsciiLenient>().unwrap(),
+        AsciiLenient::Other
+    );
+    assert_eq!(
+        "OTHER".parse::<AsciiLenient>().unwrap(),
+        AsciiLenient::Other
+    );
+    assert_err::<AsciiLenient>("STRASSE", &["Straße", "other"]);
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str("({x}, {y})")]
+struct Point2D {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str("{1}x{0}")]
+struct Size(u32, u32);
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str("{{{value}}}")]
+struct Braced {
+    value: u8,
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str("{}..{}")]
+struct Range<T>(T, T);
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str("unit")]
+struct Unit;
+
+#[test]
+fn struct_format() {
     assert_eq!(
-        "baz".parse::<EnumNoFields>().unwrap_err().to_string(),
-        "Invalid `EnumNoFields` string representation",
+        "(1, -2)".parse::<Point2D>().unwrap(),
+        Point2D { x: 1, y: -2 }
     );
+    assert_eq!("640x480".parse::<Size>().unwrap(), Size(480, 640));
+    assert_eq!("{42}".parse::<Braced>().unwrap(), Braced { value: 42 });
+    assert_eq!("1..2".parse::<Range<u8>>().unwrap(), Range(1, 2));
+    assert_eq!("unit".parse::<Unit>().unwrap(), Unit);
+    for input in ["(1,-2)", "1, 2", "(1, 2", "(1, 2))", "(a, 2)", "(1, 2, 3)"] {
+        assert_err::<Point2D>(input, &["({x}, {y})"]);
+    }
+    assert!("units".parse::<Unit>().is_err());
+    assert!("1..".parse::<Range<u8>>().is_err());
+}
+
+#[cfg(feature = "display")]
+#[test]
+fn struct_format_display_roundtrip() {
+    use derive_more::Display;
+
+    #[derive(Debug, Display, FromStr, PartialEq, Eq)]
+    #[display("{x}:{y}")]
+    #[from_str("{x}:{y}")]
+    struct Pair {
+        x: u8,
+        y: i64,
+    }
+
+    let pair = Pair { x: 7, y: -9 };
+    assert_eq!(pair.to_string().parse::<Pair>().unwrap(), pair);
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+enum Shape {
+    Empty,
+    #[from_str("circle({0})")]
+    Circle(u32),
+    #[from_str("rect({w}x{h})")]
+    Rect {
+        w: u32,
+        h: u32,
+    },
+}
+
+#[test]
+fn enum_format() {
+    assert_eq!("empty".parse::<Shape>().unwrap(), Shape::Empty);
+    assert_eq!("circle(5)".parse::<Shape>().unwrap(), Shape::Circle(5));
+    assert_eq!(
+        "rect(2x3)".parse::<Shape>().unwrap(),
+        Shape::Rect { w: 2, h: 3 },
+    );
+    for input in ["circle", "Circle(5)", "rect(2x)", "rect(2x3"] {
+        assert_err::<Shape>(input, &["Empty", "circle({0})", "rect({w}x{h})"]);
+    }
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str(try_each)]
+enum Dimension {
+    Size(u32),
+    Named(String),
+    Auto,
+}
+
+#[test]
+fn enum_try_each() {
+    assert_eq!("42".parse::<Dimension>().unwrap(), Dimension::Size(42));
+    assert_eq!("AUTO".parse::<Dimension>().unwrap(), Dimension::Auto);
+    assert_eq!(
+        "wide".parse::<Dimension>().unwrap(),
+        Dimension::Named("wide".to_string()),
+    );
+}
+
+#[derive(Debug, FromStr, PartialEq, Eq)]
+#[from_str(try_each, rename_all = "lowercase")]
+enum Value<T> {
+    Flag(bool),
+    Number {
+        value: T,
+    },
+    #[from_str("[{0}]")]
+    List(T),
+    Nothing,
+}
+
+#[test]
+fn enum_try_each_generic() {
+    assert_eq!("true".parse::<Value<i8>>().unwrap(), Value::Flag(true));
+    assert_eq!(
+        "-3".parse::<Value<i8>>().unwrap(),
+        Value::Number { value: -3 },
+    );
+    assert_eq!("[3]".parse::<Value<i8>>().unwrap(), Value::List(3));
+    assert_eq!("nothing".parse::<Value<i8>>().unwrap(), Value::Nothing);
+    assert_err::<Value<i8>>("300", &["[{0}]", "nothing"]);
+}
+
+mod custom_error {
+    use core::num::ParseIntError;
+
+    use derive_more::{FromStr, FromStrError};
+
+    #[derive(Debug, Eq, PartialEq)]
+    enum Error {
+        Int(ParseIntError),
+        Unknown(&'static str),
+    }
+
+    impl From<FromStrError> for Error {
+        fn from(err: FromStrError) -> Self {
+            Self::Unknown(err.type_name())
+        }
+    }
+
+    impl From<ParseIntError> for Error {
+        fn from(err: ParseIntError) -> Self {
+            Self::Int(err)
+        }
+    }
+
+    fn unknown(err: FromStrError) -> Error {
+        Error::Unknown(err.expected()[0])
+    }
+
+    #[derive(Debug, FromStr, PartialEq, Eq)]
+    #[from_str(error(Error, Error::Int))]
+    struct Port(u16);
+
+    #[derive(Debug, FromStr, PartialEq, Eq)]
+    #[from_str(error(Error))]
+    struct Id {
+        id: u16,
+    }
+
+    #[derive(Debug, FromStr, PartialEq, Eq)]
+    #[from_str("{0}:{1}", error(Error))]
+    struct Address(u8, Port);
+
+    #[derive(Debug, FromStr, PartialEq, Eq)]
+    #[from_str(error(Error))]
+    enum Mode {
+        Fast,
+        Slow,
+    }
+
+    #[derive(Debug, FromStr, PartialEq, Eq)]
+    #[from_str(error(Error, unknown))]
+    enum Flag {
+        On,
+        Off,
+    }
+
+    #[test]
+    fn struct_error() {
+        assert_eq!("80".parse::<Port>().unwrap(), Port(80));
+        assert_eq!(
+            "port".parse::<Port>().unwrap_err(),
+            Error::Int("port".parse::<u16>().unwrap_err()),
+        );
+        assert_eq!("1".parse::<Id>().unwrap(), Id { id: 1 });
+        assert!(matches!("-1".parse::<Id>().unwrap_err(), Error::Int(_)));
+        assert_eq!("1:80".parse::<Address>().unwrap(), Address(1, Port(80)));
+        assert_eq!(
+            "1-80".parse::<Address>().unwrap_err(),
+            Error::Unknown("Address"),
+        );
+    }
+
+    #[test]
+    fn enum_error() {
+        assert_eq!("fast".parse::<Mode>().unwrap(), Mode::Fast);
+        assert_eq!("other".parse::<Mode>().unwrap_err(), Error::Unknown("Mode"));
+        assert_eq!("on".parse::<Flag>().unwrap(), Flag::On);
+        assert_eq!("other".parse::<Flag>().unwrap_err(), Error::Unknown("On"));
+    }
+}
+
+mod validated {
+    use core::num::ParseIntError;
+
+    use derive_more::FromStr;
+
+    #[derive(Debug, Eq, PartialEq)]
+    enum Error {
+        Int(ParseIntError),
+        ZeroPort,
+    }
+
+    impl From<ParseIntError> for Error {
+        fn from(err: ParseIntError) -> Self {
+            Self::Int(err)
+        }
+    }
+
+    fn non_zero(port: &u16) -> Result<(), Error> {
+        if *port == 0 {
+            Err(Error::ZeroPort)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, FromStr, PartialEq, Eq)]
+    #[from_str(error(Error), validate = non_zero)]
+    struct Port(u16);
+
+    #[derive(Debug, FromStr, PartialEq, Eq)]
+    #[from_str(error(Error))]
+    #[from_str(validate = non_zero)]
+    struct NamedPort {
+        port: u16,
+    }
+
+    #[derive(Debug, FromStr, PartialEq, Eq)]
+    #[from_str(error(Error, Error::Int), validate = non_zero)]
+    struct MappedPort(u16);
+
+    #[test]
+    fn struct_with_validate() {
+        assert_eq!("80".parse::<Port>().unwrap(), Port(80));
+        assert_eq!("0".parse::<Port>().unwrap_err(), Error::ZeroPort);
+        assert!(matches!("-1".parse::<Port>().unwrap_err(), Error::Int(_)));
+        assert_eq!("80".parse::<NamedPort>().unwrap(), NamedPort { port: 80 });
+        assert_eq!("0".parse::<NamedPort>().unwrap_err(), Error::ZeroPort);
+        assert_eq!("0".parse::<MappedPort>().unwrap_err(), Error::ZeroPort);
+        assert!(matches!(
+            "-1".parse::<MappedPort>().unwrap_err(),
+            Error::Int(_)
+        ));
+    }
 }
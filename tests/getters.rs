@@ -0,0 +1,128 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+use derive_more::Getters;
+
+#[derive(Getters)]
+struct Unit;
+
+#[derive(Getters)]
+struct Empty {}
+
+#[derive(Getters)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn by_ref() {
+    let point = Point { x: 1, y: 2 };
+
+    let x: &i32 = point.x();
+    assert_eq!(*x, 1);
+    assert_eq!(*point.y(), 2);
+}
+
+#[derive(Getters)]
+struct User {
+    /// Name of the user.
+    name: String,
+    #[getter(copy)]
+    age: u8,
+    #[getter(mut)]
+    tags: Vec<String>,
+    #[getter(copy, mut)]
+    visits: u64,
+    #[getter(rename = "full_name")]
+    display_name: String,
+    #[getter(copy, rename = "is_admin")]
+    admin: bool,
+    #[getter(skip)]
+    password: String,
+}
+
+impl User {
+    // Would conflict with the generated getter, if not skipped.
+    fn password(&self) -> &'static str {
+        "*****"
+    }
+}
+
+#[test]
+fn field_attributes() {
+    let mut user = User {
+        name: "John".into(),
+        age: 42,
+        tags: vec![],
+        visits: 0,
+        display_name: "John Doe".into(),
+        admin: true,
+        password: "secret".into(),
+    };
+
+    assert_eq!(user.name(), "John");
+
+    let age: u8 = user.age();
+    assert_eq!(age, 42);
+
+    user.tags_mut().push("new".into());
+    assert_eq!(user.tags(), &["new"]);
+
+    *user.visits_mut() += 1;
+    let visits: u64 = user.visits();
+    assert_eq!(visits, 1);
+
+    assert_eq!(user.full_name(), "John Doe");
+    assert!(user.is_admin());
+    assert_eq!(user.password(), "*****");
+}
+
+#[derive(Getters)]
+struct Generic<'a, T: Clone, const N: usize>
+where
+    T: 'a,
+{
+    #[getter(copy)]
+    slice: &'a [T],
+    #[getter(mut)]
+    array: [T; N],
+}
+
+#[test]
+fn generic() {
+    let values = [1, 2, 3];
+    let mut generic = Generic {
+        slice: &values[..2],
+        array: [4, 5],
+    };
+
+    let slice: &[i32] = generic.slice();
+    assert_eq!(slice, &[1, 2]);
+
+    generic.array_mut()[0] = 6;
+    assert_eq!(generic.array(), &[6, 5]);
+}
+
+mod visibility {
+    mod inner {
+        use derive_more::Getters;
+
+        #[derive(Default, Getters)]
+        pub struct Private {
+            #[getter(copy)]
+            value: i32,
+        }
+    }
+
+    #[test]
+    fn accessible_outside_of_module() {
+        assert_eq!(inner::Private::default().value(), 0);
+    }
+}
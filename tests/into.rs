@@ -1512,3 +1512,52 @@ mod with_fields {
         }
     }
 }
+
+mod enums {
+    use super::*;
+
+    #[derive(Clone, Copy, Into)]
+    #[into(repr)]
+    enum Default {
+        Zero,
+        Two = 2,
+        Three,
+    }
+
+    #[derive(Clone, Copy, Into)]
+    #[into(repr)]
+    #[repr(i8)]
+    enum Signed {
+        MinusOne = -1,
+        Zero,
+    }
+
+    #[derive(Clone, Copy, Into)]
+    #[into(repr(u16, i32, i64))]
+    #[repr(u8)]
+    enum Types {
+        Zero,
+        One,
+        Ten = 10,
+    }
+
+    const THREE: isize = Default::Three.to_repr();
+
+    #[test]
+    fn repr() {
+        assert_eq!(0isize, Default::Zero.into());
+        assert_eq!(2isize, Default::Two.into());
+        assert_eq!(3, THREE);
+        assert_eq!(-1i8, Signed::MinusOne.into());
+        assert_eq!(0i8, Signed::Zero.into());
+    }
+
+    #[test]
+    fn repr_types() {
+        assert_eq!(1u16, Types::One.into());
+        assert_eq!(10i32, Types::Ten.into());
+        assert_eq!(0i64, Types::Zero.into());
+        assert_eq!(10u8, Types::Ten.to_repr());
+        assert_not_impl_any!(Types: Into<u8>);
+    }
+}
@@ -161,3 +161,84 @@ const _: () = {
     assert!(!ks.is_never_mind());
     assert!(ks.is_nothing_to_see_here());
 };
+
+#[derive(IsVariant)]
+enum State {
+    #[is_variant(group = "active")]
+    Pending,
+    #[is_variant(group = "active", and)]
+    Running(u8),
+    #[is_variant(rename = "is_done", group = "terminal")]
+    Finished,
+    #[is_variant(group = "terminal", group = "failure", and)]
+    Failed { code: i32, retries: u8 },
+    #[is_variant(ignore, group = "failure")]
+    Cancelled,
+}
+
+const _: () = {
+    let state = State::Pending;
+    assert!(state.is_pending());
+    assert!(state.is_active());
+    assert!(!state.is_terminal());
+    assert!(!state.is_failure());
+
+    let state = State::Finished;
+    assert!(state.is_done());
+    assert!(!state.is_active());
+    assert!(state.is_terminal());
+    assert!(!state.is_failure());
+
+    let state = State::Failed {
+        code: 1,
+        retries: 0,
+    };
+    assert!(state.is_failed());
+    assert!(state.is_terminal());
+    assert!(state.is_failure());
+
+    let state = State::Cancelled;
+    assert!(!state.is_terminal());
+    assert!(state.is_failure());
+};
+
+#[test]
+fn and_predicate() {
+    let state = State::Running(50);
+    assert!(state.is_running_and(|progress| *progress > 10));
+    assert!(!state.is_running_and(|progress| *progress > 90));
+    assert!(!state.is_failed_and(|_, _| true));
+
+    let state = State::Failed {
+        code: -1,
+        retries: 3,
+    };
+    assert!(state.is_failed_and(|code, retries| *code < 0 && *retries == 3));
+    assert!(!state.is_failed_and(|code, _| *code > 0));
+    assert!(!state.is_running_and(|_| true));
+}
+
+#[derive(IsVariant)]
+#[is_variant(and)]
+enum Single<T> {
+    Value(T),
+}
+
+#[derive(IsVariant)]
+#[is_variant(and)]
+enum Shape {
+    Circle(f64),
+    Rect { w: f64, h: f64 },
+    Empty,
+}
+
+#[test]
+fn enum_level_and_predicate() {
+    assert!(Single::Value(3).is_value_and(|v| *v == 3));
+    assert!(!Single::Value(3).is_value_and(|v| *v == 4));
+
+    assert!(Shape::Circle(1.0).is_circle_and(|r| *r > 0.5));
+    assert!(Shape::Rect { w: 1.0, h: 2.0 }.is_rect_and(|w, h| w < h));
+    assert!(!Shape::Empty.is_circle_and(|_| true));
+    assert!(Shape::Empty.is_empty());
+}
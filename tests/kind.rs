@@ -0,0 +1,87 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+use derive_more::Kind;
+
+#[derive(Kind)]
+enum Maybe<T> {
+    Just(T),
+    Nothing,
+}
+
+#[test]
+fn generic() {
+    assert_eq!(Maybe::Just(1).kind(), MaybeKind::Just);
+    assert_eq!(Maybe::<()>::Nothing.kind(), MaybeKind::Nothing);
+    assert_eq!(MaybeKind::from(&Maybe::Just("foo")), MaybeKind::Just);
+}
+
+#[derive(Kind)]
+enum KitchenSink<'a, T: Copy>
+where
+    T: 'a,
+{
+    Ref(&'a T),
+    Named { left: T, right: T },
+    Empty(),
+    NothingToSeeHere {},
+    Unit,
+}
+
+#[test]
+fn kitchen_sink() {
+    assert_eq!(KitchenSink::Ref(&1).kind(), KitchenSinkKind::Ref);
+    assert_eq!(
+        KitchenSink::Named { left: 1, right: 2 }.kind(),
+        KitchenSinkKind::Named,
+    );
+    assert_eq!(KitchenSink::<u8>::Empty().kind(), KitchenSinkKind::Empty);
+    let ks: KitchenSink<u8> = KitchenSink::NothingToSeeHere {};
+    assert_eq!(ks.kind(), KitchenSinkKind::NothingToSeeHere);
+    assert_eq!(KitchenSink::<u8>::Unit.kind(), KitchenSinkKind::Unit);
+}
+
+#[derive(Kind)]
+#[kind(name = Level, derive(PartialOrd, Ord))]
+enum Log {
+    Debug(&'static str),
+    Warn {
+        code: u16,
+    },
+    #[cfg(any())]
+    Disabled(u8),
+    Error,
+}
+
+const ERROR: Level = Log::Error.kind();
+
+#[test]
+fn renamed_with_derives() {
+    assert_eq!(Log::Warn { code: 1 }.kind(), Level::Warn);
+    assert!(Log::Debug("foo").kind() < Level::Warn);
+    assert_eq!(Level::Warn.max(ERROR), Level::Error);
+}
+
+#[test]
+fn hashable() {
+    use core::hash::{Hash, Hasher};
+
+    struct Counter(usize);
+
+    impl Hasher for Counter {
+        fn finish(&self) -> u64 {
+            self.0 as u64
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0 += bytes.len();
+        }
+    }
+
+    let mut hasher = Counter(0);
+    Log::Error.kind().hash(&mut hasher);
+    assert_ne!(hasher.finish(), 0);
+}
+
+#[derive(Kind)]
+enum Never {}
@@ -19,3 +19,165 @@ struct Point2D {
     x: i32,
     y: i32,
 }
+
+mod by_ref {
+    use derive_more::Mul;
+
+    /// Non-`Copy` type, supporting multiplication of both values and references.
+    #[derive(Debug, PartialEq)]
+    struct Heavy(i32);
+
+    impl core::ops::Mul for Heavy {
+        type Output = Heavy;
+
+        fn mul(self, rhs: Heavy) -> Heavy {
+            Heavy(self.0 * rhs.0)
+        }
+    }
+
+    impl core::ops::Mul<i32> for &Heavy {
+        type Output = Heavy;
+
+        fn mul(self, rhs: i32) -> Heavy {
+            Heavy(self.0 * rhs)
+        }
+    }
+
+    impl<'a> core::ops::Mul<&'a Heavy> for &'a Heavy {
+        type Output = Heavy;
+
+        fn mul(self, rhs: &'a Heavy) -> Heavy {
+            Heavy(self.0 * rhs.0)
+        }
+    }
+
+    #[derive(Debug, Mul, PartialEq)]
+    #[mul(ref)]
+    struct Pair {
+        left: Heavy,
+        right: Heavy,
+    }
+
+    #[test]
+    fn scalar() {
+        let pair = Pair {
+            left: Heavy(1),
+            right: Heavy(2),
+        };
+
+        assert_eq!(
+            &pair * 3,
+            Pair {
+                left: Heavy(3),
+                right: Heavy(6),
+            },
+        );
+    }
+
+    #[derive(Debug, Mul, PartialEq)]
+    #[mul(ref)]
+    struct Point(i32, i32);
+
+    #[test]
+    fn scalar_copy() {
+        let point = Point(1, 2);
+
+        assert_eq!(&point * 3, Point(3, 6));
+        assert_eq!(point * 2, Point(2, 4));
+    }
+
+    #[derive(Debug, Mul, PartialEq)]
+    #[mul(forward, ref)]
+    struct Forwarded(Heavy);
+
+    #[test]
+    fn forward() {
+        let a = Forwarded(Heavy(2));
+        let b = Forwarded(Heavy(3));
+
+        assert_eq!(&a * &b, Forwarded(Heavy(6)));
+        assert_eq!(a * &b, Forwarded(Heavy(6)));
+        assert_eq!(
+            Forwarded(Heavy(2)) * Forwarded(Heavy(3)),
+            Forwarded(Heavy(6)),
+        );
+    }
+}
+
+mod skip {
+    use core::marker::PhantomData;
+
+    use derive_more::Mul;
+
+    #[derive(Debug, PartialEq)]
+    struct Meters;
+
+    #[derive(Debug, Mul, PartialEq)]
+    struct Length<U> {
+        value: i32,
+        #[mul(skip)]
+        unit: PhantomData<U>,
+    }
+
+    #[test]
+    fn phantom() {
+        let length = |value| Length::<Meters> {
+            value,
+            unit: PhantomData,
+        };
+
+        assert_eq!(length(2) * 3, length(6));
+    }
+
+    #[derive(Debug, Mul, PartialEq)]
+    struct Sample(
+        i32,
+        #[mul(skip, keep = "lhs")] &'static str,
+        i32,
+        #[mul(skip, default)] u32,
+    );
+
+    #[test]
+    fn keep_and_default() {
+        assert_eq!(Sample(1, "name", 2, 3) * 2, Sample(2, "name", 4, 0));
+    }
+
+    #[derive(Debug, Mul, PartialEq)]
+    #[mul(ref)]
+    struct Labeled {
+        value: i32,
+        #[mul(skip)]
+        label: &'static str,
+    }
+
+    #[test]
+    fn by_ref() {
+        let labeled = Labeled {
+            value: 2,
+            label: "name",
+        };
+        let expected = Labeled {
+            value: 6,
+            label: "name",
+        };
+
+        assert_eq!(&labeled * 3, expected);
+        assert_eq!(labeled * 3, expected);
+    }
+
+    #[derive(Debug, Mul, PartialEq)]
+    #[mul(forward)]
+    struct Forwarded {
+        value: i32,
+        #[mul(skip, default)]
+        id: u32,
+    }
+
+    #[test]
+    fn forward() {
+        assert_eq!(
+            Forwarded { value: 2, id: 1 } * Forwarded { value: 3, id: 2 },
+            Forwarded { value: 6, id: 0 },
+        );
+    }
+}
@@ -31,3 +31,58 @@ struct MyInt2<T> {
     x: i32,
     ph: PhantomData<T>,
 }
+
+mod skip {
+    use core::marker::PhantomData;
+
+    use derive_more::MulAssign;
+
+    #[derive(Debug, PartialEq)]
+    struct Meters;
+
+    #[derive(Debug, MulAssign, PartialEq)]
+    struct Length<U> {
+        value: i32,
+        #[mul_assign(skip)]
+        unit: PhantomData<U>,
+    }
+
+    #[test]
+    fn phantom() {
+        let mut length = Length::<Meters> {
+            value: 2,
+            unit: PhantomData,
+        };
+        length *= 3;
+
+        assert_eq!(length.value, 6);
+    }
+
+    #[derive(Debug, MulAssign, PartialEq)]
+    struct Sample {
+        value: i32,
+        #[mul_assign(skip, keep = "lhs")]
+        name: &'static str,
+        #[mul_assign(skip, default)]
+        id: u32,
+    }
+
+    #[test]
+    fn keep_and_default() {
+        let mut sample = Sample {
+            value: 2,
+            name: "name",
+            id: 1,
+        };
+        sample *= 3;
+
+        assert_eq!(
+            sample,
+            Sample {
+                value: 6,
+                name: "name",
+                id: 0,
+            },
+        );
+    }
+}
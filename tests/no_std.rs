@@ -75,6 +75,51 @@ enum EnumWithUnit {
     Unit,
 }
 
+#[derive(FromStr)]
+enum EnumNoFields {
+    Foo,
+    Bar,
+    Baz,
+    BaZ,
+}
+
+#[derive(FromStr)]
+#[from_str(rename_all = "kebab-case", case = "insensitive")]
+enum RenamedInsensitive {
+    ReadOnly,
+    #[from_str(alias = "rw")]
+    ReadWrite,
+}
+
+#[derive(FromStr)]
+#[from_str(case = "ascii_insensitive")]
+enum AsciiInsensitive {
+    Foo,
+    Bar,
+}
+
+#[derive(FromStr)]
+#[from_str(case = "sensitive")]
+enum Sensitive {
+    Foo,
+    Bar,
+}
+
+#[test]
+fn enum_from_str() {
+    assert!(matches!("FOO".parse(), Ok(EnumNoFields::Foo)));
+    assert!(matches!("BaZ".parse(), Ok(EnumNoFields::BaZ)));
+    assert!("baz".parse::<EnumNoFields>().is_err());
+    assert!(matches!(
+        "Read-Only".parse(),
+        Ok(RenamedInsensitive::ReadOnly)
+    ));
+    assert!(matches!("RW".parse(), Ok(RenamedInsensitive::ReadWrite)));
+    assert!(matches!("bAR".parse(), Ok(AsciiInsensitive::Bar)));
+    assert!(matches!("Foo".parse(), Ok(Sensitive::Foo)));
+    assert!("foo".parse::<Sensitive>().is_err());
+}
+
 #[rustversion::nightly]
 mod error {
     use derive_more::{Display, Error, From};
@@ -129,3 +174,67 @@ mod error {
         assert!(CompoundError::from(Tuple::default()).source().is_none());
     }
 }
+
+#[cfg(error_in_core)]
+mod runtime_errors {
+    use core::error::Error;
+
+    use derive_more::{BinaryError, TryFrom, TryInto, TryUnwrap};
+
+    use super::{EnumNoFields, EnumWithUnit, MixedInts};
+
+    #[derive(Clone, Copy, Debug, TryFrom)]
+    #[repr(u8)]
+    #[try_from(repr)]
+    enum Flag {
+        Off,
+        On,
+    }
+
+    #[derive(Debug, TryInto, TryUnwrap)]
+    enum Value {
+        Int(i32),
+        Bool(bool),
+    }
+
+    fn as_dyn_error(err: &impl Error) -> &dyn Error {
+        err
+    }
+
+    #[test]
+    fn implement_error() {
+        let err = "qux".parse::<EnumNoFields>().err().unwrap();
+        assert!(as_dyn_error(&err).source().is_none());
+
+        let err = (!EnumWithUnit::Unit).err().unwrap();
+        assert!(as_dyn_error(&err).source().is_none());
+
+        let err = Flag::try_from(2).err().unwrap();
+        assert!(as_dyn_error(&err).source().is_none());
+
+        let err = i32::try_from(Value::Bool(true)).err().unwrap();
+        assert!(as_dyn_error(&err).source().is_none());
+
+        let err = Value::Bool(true).try_unwrap_int().err().unwrap();
+        assert!(as_dyn_error(&err).source().is_none());
+
+        let BinaryError::Mismatch(err) = (MixedInts::SmallInt(1)
+            + MixedInts::BigInt(2))
+        .err()
+        .unwrap() else {
+            unreachable!()
+        };
+        assert!(as_dyn_error(&err).source().is_none());
+    }
+
+    #[test]
+    fn binary_error_source() {
+        let err = (MixedInts::SmallInt(1) + MixedInts::BigInt(2))
+            .err()
+            .unwrap();
+        assert!(as_dyn_error(&err).source().is_some());
+
+        let err = (EnumWithUnit::Unit + EnumWithUnit::Unit).err().unwrap();
+        assert!(as_dyn_error(&err).source().is_some());
+    }
+}
@@ -27,3 +27,72 @@ enum EnumWithUnit {
     SmallInt(i32),
     Unit,
 }
+
+mod skip {
+    use core::marker::PhantomData;
+
+    use derive_more::{Neg, Not};
+
+    #[derive(Debug, PartialEq)]
+    struct Meters;
+
+    #[derive(Debug, Neg, PartialEq)]
+    struct Length<U> {
+        value: i32,
+        #[neg(skip)]
+        unit: PhantomData<U>,
+    }
+
+    #[test]
+    fn phantom() {
+        let length = |value| Length::<Meters> {
+            value,
+            unit: PhantomData,
+        };
+
+        assert_eq!(-length(1), length(-1));
+    }
+
+    #[derive(Debug, Not, PartialEq)]
+    struct Flags(
+        u8,
+        #[not(skip, keep = "lhs")] &'static str,
+        #[not(skip, default)] u32,
+    );
+
+    #[test]
+    fn keep_and_default() {
+        assert_eq!(
+            !Flags(0b1111_0000, "name", 1),
+            Flags(0b0000_1111, "name", 0)
+        );
+    }
+
+    #[derive(Debug, Neg, PartialEq)]
+    enum Reading {
+        Temperature(i32, #[neg(skip)] &'static str),
+        Pressure {
+            value: i64,
+            #[neg(skip, default)]
+            sensor: u8,
+        },
+    }
+
+    #[test]
+    fn enum_variants() {
+        assert_eq!(
+            -Reading::Temperature(20, "name"),
+            Reading::Temperature(-20, "name"),
+        );
+        assert_eq!(
+            -Reading::Pressure {
+                value: 1,
+                sensor: 1,
+            },
+            Reading::Pressure {
+                value: -1,
+                sensor: 0,
+            },
+        );
+    }
+}
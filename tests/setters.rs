@@ -0,0 +1,108 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::ToOwned as _, string::String, vec, vec::Vec};
+
+use derive_more::Setters;
+
+#[derive(Setters)]
+struct Unit;
+
+#[derive(Setters)]
+struct Empty {}
+
+#[derive(Debug, Default, PartialEq, Setters)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn set() {
+    let mut point = Point::default();
+    point.set_x(1);
+    point.set_y(2);
+
+    assert_eq!(point, Point { x: 1, y: 2 });
+}
+
+#[test]
+fn with() {
+    let point = Point::default().with_x(1).with_y(2).with_x(3);
+
+    assert_eq!(point, Point { x: 3, y: 2 });
+}
+
+#[derive(Debug, Default, PartialEq, Setters)]
+struct Request {
+    /// URL to send the request to.
+    #[setter(into)]
+    url: String,
+    #[setter(rename = "timeout_secs")]
+    timeout: u64,
+    #[setter(into, rename = "header")]
+    headers: Vec<String>,
+    #[setter(skip)]
+    retries: u8,
+}
+
+impl Request {
+    // Would conflict with the generated setter, if not skipped.
+    fn set_retries(&mut self, retries: u8) {
+        self.retries = retries.min(3);
+    }
+}
+
+#[test]
+fn field_attributes() {
+    let mut req = Request::default()
+        .with_url("https://example.com")
+        .with_timeout_secs(30)
+        .with_header(vec!["Accept: */*".to_owned()]);
+    req.set_url("https://example.org".to_owned());
+    req.set_timeout_secs(60);
+    req.set_retries(5);
+
+    assert_eq!(
+        req,
+        Request {
+            url: "https://example.org".into(),
+            timeout: 60,
+            headers: vec!["Accept: */*".into()],
+            retries: 3,
+        },
+    );
+}
+
+#[derive(Debug, PartialEq, Setters)]
+struct Generic<'a, T: Clone, const N: usize>
+where
+    T: 'a,
+{
+    slice: &'a [T],
+    #[setter(into)]
+    array: [T; N],
+}
+
+#[test]
+fn generic() {
+    let values = [1, 2, 3];
+    let mut generic = Generic {
+        slice: &values[..1],
+        array: [4, 5],
+    }
+    .with_slice(&values[1..]);
+    generic.set_array([6, 7]);
+
+    assert_eq!(
+        generic,
+        Generic {
+            slice: &[2, 3],
+            array: [6, 7],
+        },
+    );
+}
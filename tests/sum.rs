@@ -32,3 +32,82 @@ impl ::core::ops::Add for Point2D {
         }
     }
 }
+
+mod skip {
+    use core::marker::PhantomData;
+
+    use derive_more::{Product, Sum};
+
+    #[derive(Debug, PartialEq)]
+    struct Meters;
+
+    #[derive(Debug, PartialEq, Sum)]
+    struct Length<U> {
+        value: i32,
+        #[sum(skip)]
+        unit: PhantomData<U>,
+    }
+
+    // `Add` implementation is required for `Sum`.
+    impl<U> ::core::ops::Add for Length<U> {
+        type Output = Self;
+        #[inline]
+        fn add(self, rhs: Self) -> Self {
+            Self {
+                value: self.value + rhs.value,
+                unit: self.unit,
+            }
+        }
+    }
+
+    #[test]
+    fn phantom() {
+        let length = |value| Length::<Meters> {
+            value,
+            unit: PhantomData,
+        };
+
+        assert_eq!(
+            [length(1), length(2)].into_iter().sum::<Length<_>>(),
+            length(3)
+        );
+    }
+
+    #[derive(Debug, PartialEq, Product)]
+    struct Sample {
+        value: i32,
+        #[product(skip, keep = "lhs")]
+        name: &'static str,
+        #[product(skip, default)]
+        id: u32,
+    }
+
+    // `Mul` implementation is required for `Product`.
+    impl ::core::ops::Mul for Sample {
+        type Output = Self;
+        #[inline]
+        fn mul(self, rhs: Self) -> Self {
+            Self {
+                value: self.value * rhs.value,
+                name: self.name,
+                id: self.id.max(rhs.id),
+            }
+        }
+    }
+
+    #[test]
+    fn keep_and_default() {
+        let sample = |value, name, id| Sample { value, name, id };
+
+        assert_eq!(
+            [sample(2, "first", 1), sample(3, "second", 2)]
+                .into_iter()
+                .product::<Sample>(),
+            sample(6, "first", 2),
+        );
+        assert_eq!(
+            core::iter::empty::<Sample>().product::<Sample>(),
+            sample(1, "", 0),
+        );
+    }
+}
@@ -77,3 +77,147 @@ fn test_discriminants_on_enum_with_fields() {
     assert!(Enum::try_from(-14).is_err());
     assert_eq!(Enum::EmptyTuple(), Enum::try_from(-13).unwrap());
 }
+
+mod validated {
+    use core::num::TryFromIntError;
+
+    use derive_more::TryFrom;
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct ZeroPort;
+
+    #[derive(Debug, Eq, PartialEq)]
+    enum Error {
+        Overflow(TryFromIntError),
+        ZeroPort,
+    }
+
+    impl From<TryFromIntError> for Error {
+        fn from(err: TryFromIntError) -> Self {
+            Self::Overflow(err)
+        }
+    }
+
+    impl From<ZeroPort> for Error {
+        fn from(_: ZeroPort) -> Self {
+            Self::ZeroPort
+        }
+    }
+
+    fn non_zero(port: &u16) -> Result<(), ZeroPort> {
+        if *port == 0 {
+            Err(ZeroPort)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(TryFrom, Debug, Eq, PartialEq)]
+    #[try_from(u16, validate = non_zero, error(ZeroPort))]
+    struct Port(u16);
+
+    #[derive(TryFrom, Debug, Eq, PartialEq)]
+    #[try_from(u16, u32, validate = non_zero, error(Error))]
+    struct NamedPort {
+        port: u16,
+    }
+
+    #[derive(TryFrom, Debug, Eq, PartialEq)]
+    #[try_from(u32, validate = self::non_zero, error(Error, Error::Overflow))]
+    struct MappedPort(u16);
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Digit(u8);
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct NotDigit;
+
+    impl TryFrom<char> for Digit {
+        type Error = NotDigit;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            c.to_digit(10).map(|d| Self(d as u8)).ok_or(NotDigit)
+        }
+    }
+
+    fn non_zero_digit(digit: &Digit) -> Result<(), NotDigit> {
+        if digit.0 == 0 {
+            Err(NotDigit)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(TryFrom, Debug, Eq, PartialEq)]
+    #[try_from(char, validate = non_zero_digit)]
+    struct NonZeroDigit(Digit);
+
+    #[test]
+    fn struct_with_validate() {
+        assert_eq!(Port::try_from(80).unwrap(), Port(80));
+        assert_eq!(Port::try_from(0).unwrap_err(), ZeroPort);
+
+        assert_eq!(NamedPort::try_from(80u32).unwrap(), NamedPort { port: 80 });
+        assert_eq!(
+            NamedPort::try_from(8080u16).unwrap(),
+            NamedPort { port: 8080 }
+        );
+        assert_eq!(NamedPort::try_from(0u16).unwrap_err(), Error::ZeroPort);
+        assert!(matches!(
+            NamedPort::try_from(65536u32).unwrap_err(),
+            Error::Overflow(_),
+        ));
+
+        assert_eq!(MappedPort::try_from(1u32).unwrap(), MappedPort(1));
+        assert_eq!(MappedPort::try_from(0u32).unwrap_err(), Error::ZeroPort);
+        assert!(matches!(
+            MappedPort::try_from(65536u32).unwrap_err(),
+            Error::Overflow(_),
+        ));
+    }
+
+    #[test]
+    fn struct_with_validate_without_error() {
+        assert_eq!(NonZeroDigit::try_from('7').unwrap(), NonZeroDigit(Digit(7)));
+        assert_eq!(NonZeroDigit::try_from('0').unwrap_err(), NotDigit);
+        assert_eq!(NonZeroDigit::try_from('x').unwrap_err(), NotDigit);
+    }
+}
+
+#[test]
+fn enum_with_repr_types() {
+    #[derive(TryFrom, Clone, Copy, Debug, Eq, PartialEq)]
+    #[try_from(repr(u8, u16, i32))]
+    #[repr(u8)]
+    enum Enum {
+        A,
+        B = 200,
+        C,
+        Field(u8),
+    }
+
+    assert_eq!(Enum::A, Enum::try_from(0u8).unwrap());
+    assert_eq!(Enum::B, Enum::try_from(200u8).unwrap());
+    assert_eq!(Enum::C, Enum::try_from(201u16).unwrap());
+    assert_eq!(Enum::A, Enum::try_from(0i32).unwrap());
+    assert!(Enum::try_from(202u8).is_err());
+    // Values out of the `u8` range, which would match after truncation.
+    assert_eq!(Enum::try_from(256u16).unwrap_err().input, 256);
+    assert_eq!(Enum::try_from(456i32).unwrap_err().input, 456);
+    assert_eq!(Enum::try_from(-56i32).unwrap_err().input, -56);
+}
+
+#[test]
+fn enum_with_repr_types_without_repr() {
+    #[derive(TryFrom, Clone, Copy, Debug, Eq, PartialEq)]
+    #[try_from(repr(i8, u64))]
+    enum Enum {
+        A = -1,
+        B,
+        C,
+    }
+
+    assert_eq!(Enum::A, Enum::try_from(-1i8).unwrap());
+    assert_eq!(Enum::C, Enum::try_from(1u64).unwrap());
+    assert!(Enum::try_from(u64::MAX).is_err());
+}
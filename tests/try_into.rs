@@ -239,3 +239,89 @@ fn test_try_into() {
     );
     assert!(matches!(i.try_into().unwrap(), ()));
 }
+
+#[test]
+fn test_try_into_error_expected_variants() {
+    let err = i64::try_from(MixedInts::SmallInt(42)).unwrap_err();
+    assert_eq!(err.variant_name(), "SmallInt");
+    assert_eq!(
+        err.expected_variants(),
+        [
+            "NamedBigInt",
+            "UnsignedWithIgnoredField",
+            "NamedUnsignedWithIgnoredField",
+        ],
+    );
+
+    let err = <&u32>::try_from(&MixedInts::Unit2).unwrap_err();
+    assert_eq!(err.variant_name(), "Unit2");
+    assert_eq!(err.expected_variants(), ["Unsigned", "NamedUnsigned"]);
+}
+
+mod custom_error {
+    use derive_more::{TryInto, TryIntoError};
+
+    #[derive(Debug, Eq, PartialEq)]
+    enum ConvError {
+        WrongVariant(&'static str),
+    }
+
+    impl<T> From<TryIntoError<T>> for ConvError {
+        fn from(err: TryIntoError<T>) -> Self {
+            Self::WrongVariant(err.variant_name())
+        }
+    }
+
+    #[derive(TryInto, Clone, Copy, Debug)]
+    #[try_into(owned, ref, error(ConvError))]
+    enum Value {
+        Int(i32),
+        Float(f32),
+    }
+
+    #[test]
+    fn from_conversion() {
+        assert_eq!(i32::try_from(Value::Int(1)), Ok(1));
+        assert_eq!(
+            i32::try_from(Value::Float(1.0)),
+            Err(ConvError::WrongVariant("Float")),
+        );
+        assert_eq!(
+            <&f32>::try_from(&Value::Int(1)),
+            Err(ConvError::WrongVariant("Int")),
+        );
+    }
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Mismatch {
+        expected: &'static [&'static str],
+        got: &'static str,
+    }
+
+    fn to_mismatch<T>(err: TryIntoError<T>) -> Mismatch {
+        Mismatch {
+            expected: err.expected_variants(),
+            got: err.variant_name(),
+        }
+    }
+
+    #[derive(TryInto, Clone, Copy, Debug)]
+    #[try_into(error(Mismatch, to_mismatch))]
+    enum Token {
+        Ident(char),
+        Keyword(char),
+        Number(u8),
+    }
+
+    #[test]
+    fn map_function() {
+        assert_eq!(u8::try_from(Token::Number(7)), Ok(7));
+        assert_eq!(
+            char::try_from(Token::Number(7)),
+            Err(Mismatch {
+                expected: &["Ident", "Keyword"],
+                got: "Number",
+            }),
+        );
+    }
+}
@@ -5,7 +5,7 @@
 extern crate alloc;
 
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 
 use derive_more::TryUnwrap;
 
@@ -128,3 +128,114 @@ pub fn test_try_unwrap_mut_2() {
 
     assert_eq!(value, Tuple::Double(255, 256));
 }
+
+#[derive(TryUnwrap)]
+#[derive(Debug, PartialEq)]
+#[try_unwrap(option, ref, ref_mut)]
+enum Event<T> {
+    Idle,
+    Key(char),
+    Move(T, T),
+}
+
+/// Single variant enum with `Option`-returning accessors
+#[derive(TryUnwrap)]
+#[try_unwrap(option)]
+enum SingleOption {
+    Value(i32),
+}
+
+#[test]
+pub fn test_option_accessors() {
+    assert_eq!(Event::<i32>::Key('a').into_key(), Some('a'));
+    assert_eq!(Event::<i32>::Idle.into_key(), None);
+    assert_eq!(Event::<i32>::Idle.into_idle(), Some(()));
+    assert_eq!(Event::Move(1, 2).as_move(), Some((&1, &2)));
+    assert_eq!(Event::Move(1, 2).as_key(), None);
+    assert_eq!(SingleOption::Value(7).into_value(), Some(7));
+
+    let events = [Event::Key('a'), Event::Move(1, 2), Event::Key('b')];
+    let keys = events.iter().filter_map(Event::as_key).collect::<String>();
+    assert_eq!(keys, "ab");
+
+    let mut event = Event::Move(1, 2);
+    if let Some((x, y)) = event.as_move_mut() {
+        *x += 10;
+        *y += 20;
+    }
+    assert_eq!(event, Event::Move(11, 22));
+    assert_eq!(event.as_key_mut(), None);
+
+    // `TryUnwrap` methods are still generated.
+    assert_eq!(event.try_unwrap_move().ok(), Some((11, 22)));
+}
+
+#[derive(TryUnwrap)]
+#[derive(Debug, PartialEq)]
+#[try_unwrap(named, option, ref, ref_mut)]
+enum Shape<T> {
+    Circle { radius: T },
+    Rect { width: T, height: T },
+    Label(&'static str),
+}
+
+#[test]
+pub fn test_try_unwrap_named() {
+    let ShapeRectFields { width, height } = Shape::Rect {
+        width: 1,
+        height: 2,
+    }
+    .try_unwrap_rect()
+    .unwrap();
+    assert_eq!((width, height), (1, 2));
+
+    let circle = Shape::Circle { radius: 3 };
+    assert_eq!(circle.try_unwrap_circle_ref().unwrap().radius, &3);
+    assert_eq!(circle.as_circle().map(|c| *c.radius), Some(3));
+    assert!(circle.as_rect().is_none());
+    assert_eq!(
+        circle.try_unwrap_rect().map(|_| ()).unwrap_err().input,
+        Shape::Circle { radius: 3 },
+    );
+
+    let mut rect = Shape::Rect {
+        width: 1,
+        height: 2,
+    };
+    if let Some(ShapeRectFieldsMut { width, .. }) = rect.as_rect_mut() {
+        *width = 5;
+    }
+    assert_eq!(rect.into_rect().map(|r| r.width), Some(5));
+    assert_eq!(Shape::<u8>::Label("foo").into_label(), Some("foo"));
+}
+
+#[cfg(feature = "unwrap")]
+mod with_unwrap {
+    use derive_more::{TryUnwrap, Unwrap};
+
+    #[derive(TryUnwrap, Unwrap)]
+    #[try_unwrap(named)]
+    #[unwrap(named)]
+    enum Both {
+        Point {
+            x: i32,
+            y: i32,
+        },
+        #[unwrap(ignore)]
+        Size {
+            w: u32,
+            h: u32,
+        },
+    }
+
+    #[test]
+    fn shared_view_structs() {
+        let BothPointFields { x, y } = Both::Point { x: 1, y: 2 }.unwrap_point();
+        assert_eq!((x, y), (1, 2));
+        let BothSizeFields { w, h } = Both::Size { w: 3, h: 4 }
+            .try_unwrap_size()
+            .map_err(|_| ())
+            .unwrap();
+        assert_eq!((w, h), (3, 4));
+    }
+}
@@ -117,3 +117,135 @@ pub fn test_unwrap_mut_2() {
 
     assert_eq!(value, Tuple::Single(256));
 }
+
+#[test]
+pub fn test_expect() {
+    assert_eq!(Maybe::Just(1).expect_just("must be `Just`"), 1);
+    assert_eq!(Maybe::Just(42).expect_just_ref("must be `Just`"), &42);
+
+    let mut value = Tuple::Double(1, 2);
+    *value.expect_double_mut("must be `Double`").0 = 3;
+    assert_eq!(value, Tuple::Double(3, 2));
+}
+
+#[test]
+#[should_panic(expected = "must be `Just`")]
+pub fn test_expect_panic() {
+    Maybe::<()>::Nothing.expect_just("must be `Just`");
+}
+
+#[test]
+#[should_panic(expected = "must be `Nothing`")]
+pub fn test_expect_mut_panic() {
+    Maybe::Just(2).expect_nothing_mut("must be `Nothing`");
+}
+
+#[derive(Unwrap)]
+#[unwrap(ref)]
+enum Protocol {
+    Http1(u8),
+    #[unwrap(rename = "http2")]
+    Http2(u8),
+    #[unwrap(rename = "ws")]
+    WebSocket,
+}
+
+#[test]
+pub fn test_unwrap_renamed() {
+    assert_eq!(Protocol::Http1(1).unwrap_http_1(), 1);
+    assert_eq!(Protocol::Http2(2).unwrap_http2(), 2);
+    assert_eq!(Protocol::Http2(2).expect_http2_ref("must be HTTP/2"), &2);
+    Protocol::WebSocket.unwrap_ws();
+    Protocol::WebSocket.expect_ws("must be WebSocket");
+}
+
+#[derive(Unwrap)]
+enum Version {
+    V1(u8),
+    #[unwrap(rename = "v2_0")]
+    V2(u8),
+}
+
+#[test]
+pub fn test_unwrap_renamed_keeps_other_variants() {
+    assert_eq!(Version::V1(1).unwrap_v_1(), 1);
+    assert_eq!(Version::V2(2).unwrap_v2_0(), 2);
+}
+
+#[test]
+#[should_panic(
+    expected = "called `Protocol::unwrap_http2()` on a `Protocol::Http1` value"
+)]
+pub fn test_unwrap_renamed_panic() {
+    Protocol::Http1(1).unwrap_http2();
+}
+
+#[derive(Unwrap)]
+#[unwrap(ref, ref_mut)]
+enum Shape<T, U> {
+    Circle { radius: T },
+    Rect { width: T, height: T },
+    Label(U),
+}
+
+#[test]
+pub fn test_unwrap_named_as_tuple() {
+    assert_eq!(Shape::<_, ()>::Circle { radius: 2 }.unwrap_circle(), 2);
+    assert_eq!(
+        Shape::<_, ()>::Rect {
+            width: 1,
+            height: 2,
+        }
+        .unwrap_rect(),
+        (1, 2),
+    );
+}
+
+#[derive(Unwrap)]
+#[unwrap(named, ref, ref_mut)]
+enum NamedShape<'a, T, U: 'a, const N: usize>
+where
+    T: Copy,
+    U: ?Sized,
+{
+    /// Circle.
+    Circle {
+        /// Radius of the circle.
+        radius: T,
+    },
+    Rect {
+        width: T,
+        height: T,
+    },
+    Label {
+        text: &'a U,
+    },
+    Polygon {
+        points: [T; N],
+    },
+    Empty,
+}
+
+#[test]
+pub fn test_unwrap_named() {
+    let rect: NamedShape<'_, _, str, 0> = NamedShape::Rect {
+        width: 1,
+        height: 2,
+    };
+    let NamedShapeRectFields { width, height } = rect.unwrap_rect();
+    assert_eq!((width, height), (1, 2));
+
+    let circle: NamedShape<'_, _, str, 0> = NamedShape::Circle { radius: 3 };
+    let NamedShapeCircleFieldsRef { radius } = circle.unwrap_circle_ref();
+    assert_eq!(*radius, 3);
+
+    let label: NamedShape<'_, u8, _, 0> = NamedShape::Label { text: "foo" };
+    assert_eq!(label.unwrap_label_ref().text, &"foo");
+
+    let mut polygon: NamedShape<'_, _, str, 2> = NamedShape::Polygon { points: [1, 2] };
+    let NamedShapePolygonFieldsMut { points } = polygon.unwrap_polygon_mut();
+    points[0] = 10;
+    assert_eq!(polygon.unwrap_polygon().points, [10, 2]);
+
+    NamedShape::<'_, u8, str, 0>::Empty.unwrap_empty();
+}
@@ -0,0 +1,153 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+use derive_more::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[test]
+fn plain() {
+    assert_eq!(Direction::COUNT, 4);
+    assert_eq!(
+        Direction::VARIANTS,
+        &[
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ],
+    );
+    assert_eq!(
+        Direction::VARIANT_NAMES,
+        &["North", "East", "South", "West"]
+    );
+    assert_eq!(Direction::South.variant_name(), "South");
+}
+
+#[derive(Debug, PartialEq, Variants)]
+#[from_str(rename_all = "kebab-case")]
+enum Mode {
+    ReadOnly,
+    #[from_str(rename = "rw")]
+    ReadWrite,
+    Empty {},
+    Nothing(),
+}
+
+const NAMES: [&str; Mode::COUNT] = {
+    let mut names = [""; Mode::COUNT];
+    let mut i = 0;
+    while i < Mode::COUNT {
+        names[i] = Mode::VARIANTS[i].variant_name();
+        i += 1;
+    }
+    names
+};
+
+#[test]
+fn renamed() {
+    assert_eq!(Mode::COUNT, 4);
+    assert_eq!(
+        Mode::VARIANT_NAMES,
+        &["read-only", "rw", "empty", "nothing"]
+    );
+    assert_eq!(Mode::ReadWrite.variant_name(), "rw");
+    assert_eq!(Mode::Empty {}.variant_name(), "empty");
+    assert_eq!(NAMES, Mode::VARIANT_NAMES);
+}
+
+#[derive(Variants)]
+enum Never {}
+
+#[test]
+fn empty() {
+    assert_eq!(Never::COUNT, 0);
+    assert!(Never::VARIANTS.is_empty());
+    assert!(Never::VARIANT_NAMES.is_empty());
+}
+
+#[derive(Debug, PartialEq, Variants)]
+#[from_str(rename_all = "snake_case")]
+enum Level {
+    #[from_str("dbg")]
+    Debug,
+    #[from_str("{0}")]
+    Info,
+    #[display("WARN")]
+    Warning,
+    #[display("error: {}", "failed")]
+    #[from_str(rename = "err")]
+    Error,
+    #[display("{{fatal}}")]
+    Fatal,
+}
+
+#[test]
+fn renamed_by_format() {
+    assert_eq!(
+        Level::VARIANT_NAMES,
+        &["dbg", "info", "WARN", "err", "{fatal}"],
+    );
+}
+
+#[cfg(feature = "from_str")]
+mod from_str {
+    use derive_more::{FromStr, Variants};
+
+    #[derive(Debug, FromStr, PartialEq, Variants)]
+    #[from_str(rename_all = "snake_case", case = "sensitive")]
+    enum Color {
+        DarkRed,
+        #[from_str(rename = "green", alias = "lime")]
+        LightGreen,
+        #[from_str("blue")]
+        Blue,
+        #[from_str("{{cyan}}")]
+        Cyan,
+    }
+
+    #[test]
+    fn agrees_with_from_str() {
+        assert_eq!(
+            Color::VARIANT_NAMES,
+            &["dark_red", "green", "blue", "{cyan}"],
+        );
+        for (variant, name) in Color::VARIANTS.iter().zip(Color::VARIANT_NAMES) {
+            assert_eq!(&name.parse::<Color>().unwrap(), variant);
+        }
+        assert_eq!("lime".parse::<Color>().unwrap(), Color::LightGreen);
+    }
+}
+
+#[cfg(feature = "display")]
+mod display {
+    use derive_more::{Display, Variants};
+
+    #[derive(Display, Variants)]
+    enum Shape {
+        #[display("circle")]
+        Circle,
+        #[display("square")]
+        Square,
+        Triangle,
+        #[display("{{hexagon}}")]
+        Hexagon,
+    }
+
+    #[test]
+    fn agrees_with_display() {
+        assert_eq!(
+            Shape::VARIANT_NAMES,
+            &["circle", "square", "Triangle", "{hexagon}"],
+        );
+        for variant in Shape::VARIANTS {
+            assert_eq!(variant.to_string(), variant.variant_name());
+        }
+    }
+}